@@ -0,0 +1,142 @@
+//! Traits for collections whose length is known at compile time.
+use super::index::{SplitSlice, SplitSliceMut};
+use super::len::Len;
+
+/// A trait for collections whose length is part of their type.
+///
+/// This lets the length of a collection be checked at compile time, e.g. that a buffer is long
+/// enough to hold a key and a nonce. It is implemented for arrays and the wrappers from this
+/// crate, and [`SplitArray`] uses it to split arrays into smaller arrays without any runtime
+/// checks.
+///
+/// `LEN` must always be equal to `Len::len`.
+///
+/// # Examples
+///
+/// ```rust
+/// use len_trait::{Len, StaticLen};
+///
+/// fn key_bits<K: StaticLen>() -> usize {
+///     K::LEN * 8
+/// }
+///
+/// assert_eq!(key_bits::<[u8; 32]>(), 256);
+/// assert_eq!([0u8; 32].len(), <[u8; 32]>::LEN);
+/// ```
+///
+/// [`SplitArray`]: trait.SplitArray.html
+pub trait StaticLen: Len {
+    /// The length of every value of this type.
+    const LEN: usize;
+}
+
+impl<T, const N: usize> StaticLen for [T; N] {
+    const LEN: usize = N;
+}
+
+/// A trait for viewing a slice of any length as an array, checking its length at runtime.
+///
+/// This is automatically implemented for every collection which can be split into slices of `T`.
+pub trait AsArray<T>: SplitSlice<usize, Slice = [T]> {
+    /// Returns the collection as an array, if it has exactly `N` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::AsArray;
+    ///
+    /// let vec = vec![1, 2, 3];
+    /// assert_eq!(AsArray::as_array::<3>(&vec), Some(&[1, 2, 3]));
+    /// assert_eq!(AsArray::as_array::<2>(&vec), None);
+    /// ```
+    fn as_array<const N: usize>(&self) -> Option<&[T; N]> {
+        self.split_slice(0).1.try_into().ok()
+    }
+
+    /// Returns the collection as a mutable array, if it has exactly `N` elements.
+    fn as_array_mut<const N: usize>(&mut self) -> Option<&mut [T; N]>
+    where
+        Self: SplitSliceMut<usize>,
+    {
+        self.split_slice_mut(0).1.try_into().ok()
+    }
+
+    /// Splits the first `K` elements off as an array, if the collection is at least that long.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::AsArray;
+    ///
+    /// let packet: Box<[u8]> = Box::new([1, 2, 3, 4, 5]);
+    /// let (header, body) = packet.try_split_array::<2>().unwrap();
+    /// assert_eq!(header, &[1, 2]);
+    /// assert_eq!(body, [3, 4, 5]);
+    /// assert!(packet.try_split_array::<6>().is_none());
+    /// ```
+    fn try_split_array<const K: usize>(&self) -> Option<(&[T; K], &[T])> {
+        let whole = self.split_slice(0).1;
+        if K > whole.len() {
+            return None;
+        }
+        let (left, right) = whole.split_at(K);
+        Some((left.try_into().ok()?, right))
+    }
+}
+
+impl<T, C: ?Sized + SplitSlice<usize, Slice = [T]>> AsArray<T> for C {}
+
+/// A trait for splitting a collection of known length into an array and a slice.
+///
+/// Because the length of the collection is known at compile time, the split point is checked at
+/// compile time too: splitting past the end of the collection fails to compile, instead of
+/// panicking. This is automatically implemented for every collection which implements
+/// [`StaticLen`] and can be split into slices of `T`.
+///
+/// ```rust,compile_fail
+/// use len_trait::SplitArray;
+///
+/// let buffer = [0u8; 12];
+/// let (key, rest) = buffer.split_array::<16>();
+/// ```
+///
+/// [`StaticLen`]: trait.StaticLen.html
+pub trait SplitArray<T>: StaticLen + SplitSlice<usize, Slice = [T]> {
+    /// Splits the first `K` elements off as an array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::SplitArray;
+    ///
+    /// let buffer = [7u8; 44];
+    /// let (key, rest) = buffer.split_array::<32>();
+    /// let (nonce, rest) = rest.split_first_chunk::<12>().unwrap();
+    /// assert_eq!(key.len() + nonce.len() + rest.len(), 44);
+    /// ```
+    fn split_array<const K: usize>(&self) -> (&[T; K], &[T]) {
+        const { assert!(K <= Self::LEN, "split point is past the end of the collection") };
+        let (left, right) = self.split_slice(K);
+        (left.try_into().expect("StaticLen::LEN doesn't match the length"), right)
+    }
+
+    /// Splits the last `K` elements off as an array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::SplitArray;
+    ///
+    /// let sealed = [1u8, 2, 3, 4, 5, 6];
+    /// let (ciphertext, tag) = sealed.rsplit_array::<4>();
+    /// assert_eq!(ciphertext, [1, 2]);
+    /// assert_eq!(tag, &[3, 4, 5, 6]);
+    /// ```
+    fn rsplit_array<const K: usize>(&self) -> (&[T], &[T; K]) {
+        const { assert!(K <= Self::LEN, "split point is past the end of the collection") };
+        let (left, right) = self.split_slice(Self::LEN - K);
+        (left, right.try_into().expect("StaticLen::LEN doesn't match the length"))
+    }
+}
+
+impl<T, C: StaticLen + SplitSlice<usize, Slice = [T]>> SplitArray<T> for C {}
@@ -0,0 +1,253 @@
+//! Wrappers which let the length of a collection be read without access to it.
+use std::mem::MaybeUninit;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::capacity::{BoundedPush, Capacity, CapacityMut, MaxCapacity, SpareCapacityMut,
+                      TryCapacityMut, WithCapacity};
+use super::error::{CapacityExceeded, IndexError, ReserveError};
+use super::len::{Clear, Empty, Len, LenMut, TryLenMut};
+use super::string::CharBoundaryMut;
+
+/// A wrapper which mirrors the length of a collection in an atomic integer.
+///
+/// Every operation from this crate which can change the length of the collection updates the
+/// mirrored length, as does adding elements with `Extend`. The length can then be read through a
+/// [`LenHandle`] without access to the collection, e.g. while it's locked behind a `Mutex` by
+/// another thread.
+///
+/// The wrapped collection can be read through `Deref`, but can only be modified through the
+/// wrapper, so that the mirrored length can't become stale.
+///
+/// For collections which don't need lock-free reads, `Len` is also implemented for the guards of
+/// `Mutex` and `RwLock`, so that a locked collection can be passed directly to generic code.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::{Arc, Mutex};
+/// use std::thread;
+/// use len_trait::{AtomicLen, Len};
+///
+/// let queue = Arc::new(Mutex::new(AtomicLen::new(Vec::new())));
+/// let len = queue.lock().unwrap().handle();
+///
+/// let writer = {
+///     let queue = Arc::clone(&queue);
+///     thread::spawn(move || queue.lock().unwrap().extend(0..100))
+/// };
+/// writer.join().unwrap();
+///
+/// // Reading the length doesn't need the lock.
+/// assert_eq!(len.len(), 100);
+///
+/// fn count<C: Len>(collection: &C) -> usize {
+///     collection.len()
+/// }
+/// assert_eq!(count(&queue.lock().unwrap()), 100);
+/// ```
+///
+/// The mirrored length is updated even if an operation panics partway through.
+///
+/// ```rust
+/// use std::panic::{self, AssertUnwindSafe};
+/// use len_trait::{AtomicLen, Len};
+///
+/// let mut vec = AtomicLen::new(Vec::new());
+/// let len = vec.handle();
+///
+/// let result = panic::catch_unwind(AssertUnwindSafe(|| {
+///     vec.extend((0..10).map(|i| if i < 3 { i } else { panic!("oops") }));
+/// }));
+/// assert!(result.is_err());
+/// assert_eq!(vec.len(), 3);
+/// assert_eq!(len.len(), 3);
+/// ```
+///
+/// [`LenHandle`]: struct.LenHandle.html
+#[derive(Debug)]
+pub struct AtomicLen<C> {
+    inner: C,
+    len: Arc<AtomicUsize>,
+}
+
+impl<C: Len> AtomicLen<C> {
+    /// Wraps a collection, mirroring its length.
+    pub fn new(inner: C) -> Self {
+        let len = Arc::new(AtomicUsize::new(inner.len()));
+        AtomicLen { inner, len }
+    }
+
+    /// Returns a handle which can read the length of the collection.
+    pub fn handle(&self) -> LenHandle {
+        LenHandle { len: Arc::clone(&self.len) }
+    }
+
+    /// Unwraps the collection.
+    ///
+    /// Any handles will keep the length the collection had when it was unwrapped.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    /// Runs an operation on the collection, updating the mirrored length.
+    ///
+    /// The length is stored by a guard, so that it's updated even if the operation panics.
+    fn sync<R>(&mut self, f: impl FnOnce(&mut C) -> R) -> R {
+        let guard = SyncGuard { inner: &mut self.inner, len: &self.len };
+        f(&mut *guard.inner)
+    }
+}
+
+/// Stores the length of a collection when dropped.
+struct SyncGuard<'a, C: Len> {
+    inner: &'a mut C,
+    len: &'a AtomicUsize,
+}
+
+impl<C: Len> Drop for SyncGuard<'_, C> {
+    fn drop(&mut self) {
+        self.len.store(self.inner.len(), Ordering::Release);
+    }
+}
+
+/// Clones the collection, giving the clone a separate mirrored length.
+impl<C: Clone + Len> Clone for AtomicLen<C> {
+    fn clone(&self) -> Self {
+        AtomicLen::new(self.inner.clone())
+    }
+}
+
+impl<C: Default + Len> Default for AtomicLen<C> {
+    fn default() -> Self {
+        AtomicLen::new(C::default())
+    }
+}
+
+impl<C> Deref for AtomicLen<C> {
+    type Target = C;
+    fn deref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C> AsRef<C> for AtomicLen<C> {
+    fn as_ref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<T, C: Len + Extend<T>> Extend<T> for AtomicLen<C> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.sync(|inner| inner.extend(iter))
+    }
+}
+
+forward_shared!([] AtomicLen<C> => inner);
+
+impl<C: Clear> Clear for AtomicLen<C> {
+    fn clear(&mut self) {
+        self.sync(C::clear)
+    }
+}
+
+impl<C: LenMut> LenMut for AtomicLen<C> {
+    fn truncate(&mut self, len: usize) {
+        self.sync(|inner| inner.truncate(len))
+    }
+    fn split_off(&mut self, index: usize) -> Self {
+        AtomicLen::new(self.sync(|inner| inner.split_off(index)))
+    }
+}
+
+impl<C: TryLenMut> TryLenMut for AtomicLen<C> {
+    fn check_boundary(&self, index: usize) -> Result<(), IndexError> {
+        self.inner.check_boundary(index)
+    }
+}
+
+impl<C: CharBoundaryMut> CharBoundaryMut for AtomicLen<C> {
+    fn truncate_floor(&mut self, len: usize) {
+        self.sync(|inner| inner.truncate_floor(len))
+    }
+}
+
+impl<C: Capacity> Capacity for AtomicLen<C> {
+    fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+}
+
+impl<C: WithCapacity> WithCapacity for AtomicLen<C> {
+    fn with_capacity(capacity: usize) -> Self {
+        AtomicLen::new(C::with_capacity(capacity))
+    }
+}
+
+impl<C: CapacityMut> CapacityMut for AtomicLen<C> {
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        self.inner.reserve_exact(additional)
+    }
+    fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+}
+
+impl<C: TryCapacityMut> TryCapacityMut for AtomicLen<C> {
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+        self.inner.try_reserve(additional)
+    }
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), ReserveError> {
+        self.inner.try_reserve_exact(additional)
+    }
+}
+
+impl<C: SpareCapacityMut> SpareCapacityMut for AtomicLen<C> {
+    type Element = C::Element;
+    fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<C::Element>] {
+        self.inner.spare_capacity_mut()
+    }
+    unsafe fn set_len(&mut self, len: usize) {
+        // SAFETY: the caller upholds the same contract for the wrapped collection.
+        self.sync(|inner| unsafe { inner.set_len(len) })
+    }
+}
+
+impl<C: MaxCapacity> MaxCapacity for AtomicLen<C> {
+    fn max_capacity(&self) -> usize {
+        self.inner.max_capacity()
+    }
+}
+
+impl<T, C: BoundedPush<T>> BoundedPush<T> for AtomicLen<C> {
+    fn try_push(&mut self, value: T) -> Result<(), CapacityExceeded<T>> {
+        self.sync(|inner| inner.try_push(value))
+    }
+}
+
+/// A handle for reading the length of an [`AtomicLen`] collection from anywhere.
+///
+/// The length is a snapshot: it may change as soon as it has been read, if the collection is
+/// being modified on another thread.
+///
+/// [`AtomicLen`]: struct.AtomicLen.html
+#[derive(Clone, Debug)]
+pub struct LenHandle {
+    len: Arc<AtomicUsize>,
+}
+
+impl Empty for LenHandle {
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Len for LenHandle {
+    fn len(&self) -> usize {
+        self.len.load(Ordering::Acquire)
+    }
+}
@@ -0,0 +1,194 @@
+//! Wrappers which limit the length of a collection.
+use std::mem::MaybeUninit;
+use std::ops::Deref;
+
+use super::capacity::{BoundedPush, Capacity, CapacityMut, MaxCapacity, SpareCapacityMut,
+                      TryCapacityMut, WithCapacity};
+use super::error::{CapacityExceeded, CapacityOverflow, IndexError, ReserveError};
+use super::len::{Clear, Len, LenMut, TryLenMut};
+use super::string::CharBoundaryMut;
+
+/// A wrapper which prevents a collection from growing past `MAX` elements.
+///
+/// Every operation from this crate which can grow the collection is checked against the limit:
+/// elements can be added with [`BoundedPush::try_push`] or [`try_extend`], and capacity reserved
+/// with [`CapacityMut`] is capped so that it never exceeds what the limit allows. Operations which
+/// would exceed the limit return a [`CapacityExceeded`] error instead, and [`TryCapacityMut`]
+/// reports reservations past the limit as a [`CapacityOverflow`].
+///
+/// The wrapped collection can be read through `Deref`, but can only be modified through the
+/// wrapper, so that the limit can't be bypassed.
+///
+/// # Examples
+///
+/// ```rust
+/// use len_trait::{BoundedLen, BoundedPush, Len};
+///
+/// let mut payload: BoundedLen<Vec<u8>, 4> = BoundedLen::new(vec![1, 2]).unwrap();
+/// assert!(payload.try_push(3).is_ok());
+/// assert!(payload.try_extend(vec![4, 5]).is_err());
+/// assert_eq!(*payload, [1, 2, 3, 4]);
+///
+/// assert!(BoundedLen::<Vec<u8>, 1>::new(vec![1, 2]).is_err());
+/// ```
+///
+/// [`BoundedPush::try_push`]: ../capacity/trait.BoundedPush.html#tymethod.try_push
+/// [`try_extend`]: #method.try_extend
+/// [`CapacityMut`]: ../capacity/trait.CapacityMut.html
+/// [`CapacityExceeded`]: ../error/struct.CapacityExceeded.html
+/// [`TryCapacityMut`]: ../capacity/trait.TryCapacityMut.html
+/// [`CapacityOverflow`]: ../error/struct.CapacityOverflow.html
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BoundedLen<C, const MAX: usize> {
+    inner: C,
+}
+
+impl<C: Len, const MAX: usize> BoundedLen<C, MAX> {
+    /// Wraps a collection, returning it in an error if it is already longer than `MAX`.
+    pub fn new(inner: C) -> Result<Self, CapacityExceeded<C>> {
+        if inner.len() > MAX {
+            Err(CapacityExceeded::new(inner))
+        } else {
+            Ok(BoundedLen { inner })
+        }
+    }
+
+    /// Adds each element of the iterator to the collection, until it is full.
+    ///
+    /// If the collection fills up, the first element which didn't fit is returned in an error,
+    /// and the rest of the iterator is dropped. The elements which did fit are kept.
+    pub fn try_extend<T, I>(&mut self, iter: I) -> Result<(), CapacityExceeded<T>>
+    where
+        C: Capacity + Extend<T>,
+        I: IntoIterator<Item = T>,
+    {
+        for value in iter {
+            self.try_push(value)?;
+        }
+        Ok(())
+    }
+
+    /// Unwraps the collection.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    /// Returns how many more elements can be added before reaching the limit.
+    fn remaining(&self) -> usize {
+        MAX.saturating_sub(self.inner.len())
+    }
+}
+
+impl<C, const MAX: usize> Deref for BoundedLen<C, MAX> {
+    type Target = C;
+    fn deref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C, const MAX: usize> AsRef<C> for BoundedLen<C, MAX> {
+    fn as_ref(&self) -> &C {
+        &self.inner
+    }
+}
+
+forward_shared!([const MAX: usize] BoundedLen<C, MAX> => inner);
+
+impl<C: Clear, const MAX: usize> Clear for BoundedLen<C, MAX> {
+    fn clear(&mut self) {
+        self.inner.clear()
+    }
+}
+
+impl<C: LenMut, const MAX: usize> LenMut for BoundedLen<C, MAX> {
+    fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len)
+    }
+    fn split_off(&mut self, index: usize) -> Self {
+        BoundedLen { inner: self.inner.split_off(index) }
+    }
+}
+
+impl<C: TryLenMut, const MAX: usize> TryLenMut for BoundedLen<C, MAX> {
+    fn check_boundary(&self, index: usize) -> Result<(), IndexError> {
+        self.inner.check_boundary(index)
+    }
+}
+
+impl<C: CharBoundaryMut, const MAX: usize> CharBoundaryMut for BoundedLen<C, MAX> {
+    fn truncate_floor(&mut self, len: usize) {
+        self.inner.truncate_floor(len)
+    }
+}
+
+impl<C: Capacity, const MAX: usize> Capacity for BoundedLen<C, MAX> {
+    fn capacity(&self) -> usize {
+        self.inner.capacity().min(MAX)
+    }
+}
+
+impl<C: WithCapacity, const MAX: usize> WithCapacity for BoundedLen<C, MAX> {
+    fn with_capacity(capacity: usize) -> Self {
+        BoundedLen { inner: C::with_capacity(capacity.min(MAX)) }
+    }
+}
+
+impl<C: CapacityMut, const MAX: usize> CapacityMut for BoundedLen<C, MAX> {
+    fn reserve(&mut self, additional: usize) {
+        let additional = additional.min(self.remaining());
+        self.inner.reserve(additional)
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        let additional = additional.min(self.remaining());
+        self.inner.reserve_exact(additional)
+    }
+    fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+}
+
+impl<C: TryCapacityMut, const MAX: usize> TryCapacityMut for BoundedLen<C, MAX> {
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+        if additional > self.remaining() {
+            return Err(CapacityOverflow.into());
+        }
+        self.inner.try_reserve(additional)
+    }
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), ReserveError> {
+        if additional > self.remaining() {
+            return Err(CapacityOverflow.into());
+        }
+        self.inner.try_reserve_exact(additional)
+    }
+}
+
+impl<C: SpareCapacityMut, const MAX: usize> SpareCapacityMut for BoundedLen<C, MAX> {
+    type Element = C::Element;
+    fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<C::Element>] {
+        let remaining = self.remaining();
+        let spare = self.inner.spare_capacity_mut();
+        let len = spare.len().min(remaining);
+        &mut spare[..len]
+    }
+    unsafe fn set_len(&mut self, len: usize) {
+        assert!(len <= MAX, "length exceeds the maximum of {}", MAX);
+        // SAFETY: the caller upholds the same contract for the wrapped collection.
+        unsafe { self.inner.set_len(len) }
+    }
+}
+
+impl<C: Capacity, const MAX: usize> MaxCapacity for BoundedLen<C, MAX> {
+    fn max_capacity(&self) -> usize {
+        MAX
+    }
+}
+
+impl<T, C: Capacity + Extend<T>, const MAX: usize> BoundedPush<T> for BoundedLen<C, MAX> {
+    fn try_push(&mut self, value: T) -> Result<(), CapacityExceeded<T>> {
+        if self.remaining() == 0 {
+            return Err(CapacityExceeded::new(value));
+        }
+        self.inner.extend(Some(value));
+        Ok(())
+    }
+}
@@ -1,5 +1,8 @@
 
 //! Traits involving the capacity of a collection.
+use std::mem::MaybeUninit;
+
+use super::error::{CapacityExceeded, ReserveError};
 use super::len::Len;
 
 /// A trait for describing the capacity of a collection.
@@ -15,6 +18,9 @@ pub trait Capacity: Len {
     /// If this collection stores zero-sized types, then it effectively has infinite capacity. For
     /// this reason, those collections should have a capacity of `usize::MAX`.
     ///
+    /// Collections which also implement [`MaxCapacity`], like arrays, are the exception: their
+    /// capacity can never exceed their maximum capacity, even for zero-sized types.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -31,7 +37,13 @@ pub trait Capacity: Len {
     /// check_capacity(vec![()], true);
     /// check_capacity(vec![1, 2, 3], false);
     /// check_capacity("Hello, world!".to_string(), false);
+    ///
+    /// // An array can't grow, whatever it stores.
+    /// check_capacity([(); 4], false);
+    /// assert_eq!(Capacity::capacity(&[(); 4]), 4);
     /// ```
+    ///
+    /// [`MaxCapacity`]: trait.MaxCapacity.html
     fn capacity(&self) -> usize;
 }
 
@@ -168,4 +180,331 @@ pub trait CapacityMut: WithCapacity {
             *self = Default::default();
         }
     }
-}
\ No newline at end of file
+}
+
+/// A trait for reserving capacity, returning an error instead of panicking or aborting.
+///
+/// These have the same effect as the methods of `CapacityMut` when they succeed.
+pub trait TryCapacityMut: CapacityMut {
+    /// Ensures that the capacity is at least the current length plus `additional`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityOverflow`] if the capacity can't be represented, or [`AllocError`] if the
+    /// allocator fails to provide the memory.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::{Capacity, TryCapacityMut};
+    /// use len_trait::error::{CapacityOverflow, ReserveError};
+    ///
+    /// fn check_try_reserve<C: TryCapacityMut>(mut collection: C) {
+    ///     assert_eq!(collection.try_reserve(10), Ok(()));
+    ///     assert!(collection.capacity() >= collection.len() + 10);
+    ///     assert_eq!(
+    ///         collection.try_reserve(usize::MAX),
+    ///         Err(ReserveError::CapacityOverflow(CapacityOverflow)),
+    ///     );
+    /// }
+    ///
+    /// check_try_reserve(vec![1, 2, 3]);
+    /// check_try_reserve("Hello, world!".to_string());
+    /// ```
+    ///
+    /// Collections with a maximum capacity can't reserve past it:
+    ///
+    /// ```rust
+    /// use len_trait::{BoundedLen, TryCapacityMut};
+    /// use len_trait::error::{CapacityOverflow, ReserveError};
+    ///
+    /// let mut vec: BoundedLen<Vec<u8>, 5> = BoundedLen::new(vec![1, 2]).unwrap();
+    /// assert_eq!(vec.try_reserve(3), Ok(()));
+    /// assert_eq!(vec.try_reserve(4), Err(ReserveError::CapacityOverflow(CapacityOverflow)));
+    /// assert_eq!(vec.try_reserve_exact(4), Err(ReserveError::CapacityOverflow(CapacityOverflow)));
+    /// ```
+    ///
+    /// [`CapacityOverflow`]: ../error/struct.CapacityOverflow.html
+    /// [`AllocError`]: ../error/struct.AllocError.html
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError>;
+
+    /// Similar to `try_reserve`, adding a strong hint to not reserve capacity above what's needed.
+    ///
+    /// By default, this method just delegates to `try_reserve` unless the implementation has a
+    /// more efficient version.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `try_reserve`.
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), ReserveError> {
+        self.try_reserve(additional)
+    }
+}
+
+/// A dyn-compatible counterpart to [`CapacityMut`].
+///
+/// `CapacityMut` requires `WithCapacity`, which requires `Default` and constructs `Self`, so it
+/// can't be used as a trait object. This trait offers the methods which modify an existing
+/// collection, so that different kinds of collections can be stored together as
+/// `Box<dyn DynCapacityMut>`. It is automatically implemented for every `CapacityMut` implementor.
+///
+/// The methods are prefixed with `dyn_` so that calling them is never ambiguous with
+/// `CapacityMut`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::{HashMap, VecDeque};
+/// use len_trait::DynCapacityMut;
+///
+/// let mut buffers: Vec<Box<dyn DynCapacityMut>> = vec![
+///     Box::new(vec![1, 2, 3]),
+///     Box::new(VecDeque::<u8>::new()),
+///     Box::new(HashMap::<u8, u8>::new()),
+/// ];
+/// for buffer in &mut buffers {
+///     buffer.dyn_reserve(100);
+///     assert!(buffer.capacity() >= buffer.len() + 100);
+/// }
+/// ```
+///
+/// [`CapacityMut`]: trait.CapacityMut.html
+pub trait DynCapacityMut: Capacity {
+    /// Ensures that the capacity is at least the current length plus `additional`.
+    ///
+    /// This behaves the same as `CapacityMut::reserve`.
+    fn dyn_reserve(&mut self, additional: usize);
+
+    /// Similar to `dyn_reserve`, adding a strong hint to not reserve capacity above what's needed.
+    ///
+    /// This behaves the same as `CapacityMut::reserve_exact`.
+    fn dyn_reserve_exact(&mut self, additional: usize);
+
+    /// Reduces the capacity down as close as possible to the current length.
+    ///
+    /// This behaves the same as `CapacityMut::shrink_to_fit`.
+    fn dyn_shrink_to_fit(&mut self);
+}
+
+impl<T: CapacityMut> DynCapacityMut for T {
+    fn dyn_reserve(&mut self, additional: usize) {
+        CapacityMut::reserve(self, additional)
+    }
+    fn dyn_reserve_exact(&mut self, additional: usize) {
+        CapacityMut::reserve_exact(self, additional)
+    }
+    fn dyn_shrink_to_fit(&mut self) {
+        CapacityMut::shrink_to_fit(self)
+    }
+}
+
+/// A trait for writing directly into the spare capacity of a collection.
+///
+/// Zero-copy I/O often needs to fill a buffer's reserved, uninitialised space before marking it
+/// as part of the collection. This is only possible for collections which store their elements
+/// contiguously, like `Vec<T>`, or `String`, whose elements are bytes.
+///
+/// Obtaining the spare capacity and setting the length must take a constant amount of time and
+/// space.
+///
+/// # Examples
+///
+/// Elements which need dropping can be written too, and the wrappers from this crate only expose
+/// the part of the spare capacity they allow the collection to grow into:
+///
+/// ```rust
+/// use len_trait::{BoundedLen, Capacity, CapacityMut, SpareCapacityMut, Tracked};
+///
+/// fn push_names<C: SpareCapacityMut<Element = String>>(names: &mut C, count: usize) -> usize {
+///     let len = names.len();
+///     let spare = names.spare_capacity_mut();
+///     let count = count.min(spare.len());
+///     for (i, slot) in spare[..count].iter_mut().enumerate() {
+///         slot.write(format!("name {}", len + i));
+///     }
+///     // SAFETY: the first `count` spare elements were just initialised.
+///     unsafe { names.set_len(len + count) };
+///     count
+/// }
+///
+/// let mut vec = Vec::with_capacity(4);
+/// assert_eq!(push_names(&mut vec, 3), 3);
+/// assert_eq!(push_names(&mut vec, 3), 1);
+/// assert_eq!(vec, ["name 0", "name 1", "name 2", "name 3"]);
+///
+/// let mut bounded: BoundedLen<Vec<String>, 2> = BoundedLen::new(Vec::with_capacity(8)).unwrap();
+/// assert_eq!(bounded.spare_capacity_mut().len(), 2);
+/// assert_eq!(push_names(&mut bounded, 5), 2);
+/// assert_eq!(bounded.spare_capacity_mut().len(), 0);
+/// assert_eq!(*bounded, ["name 0", "name 1"]);
+///
+/// let mut tracked = Tracked::new(Vec::new());
+/// tracked.reserve_exact(2);
+/// assert_eq!(push_names(&mut tracked, 2), 2);
+/// assert_eq!(tracked.stats().peak_len, 2);
+/// assert_eq!(tracked.capacity(), 2);
+///
+/// // Shrinking the length back drops nothing, so the elements must be dropped by hand.
+/// let mut vec = vec!["a".to_string(), "b".to_string()];
+/// // SAFETY: the first element stays initialised, and the second is dropped below.
+/// unsafe { vec.set_len(1) };
+/// let spare = SpareCapacityMut::spare_capacity_mut(&mut vec);
+/// // SAFETY: the element was initialised, and is no longer part of the vector.
+/// drop(unsafe { spare[0].assume_init_read() });
+/// assert_eq!(vec, ["a"]);
+/// ```
+pub trait SpareCapacityMut: CapacityMut {
+    /// The type of the elements stored in the collection's buffer.
+    type Element;
+
+    /// Returns the spare capacity of the collection, i.e. the uninitialised space which follows
+    /// its elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::SpareCapacityMut;
+    ///
+    /// fn fill<C: SpareCapacityMut<Element = u8>>(buffer: &mut C, data: &[u8]) {
+    ///     buffer.reserve(data.len());
+    ///     let len = buffer.len();
+    ///     for (slot, &byte) in buffer.spare_capacity_mut().iter_mut().zip(data) {
+    ///         slot.write(byte);
+    ///     }
+    ///     // SAFETY: the first `data.len()` spare elements were just initialised.
+    ///     unsafe { buffer.set_len(len + data.len()) };
+    /// }
+    ///
+    /// let mut vec = vec![1, 2];
+    /// fill(&mut vec, &[3, 4]);
+    /// assert_eq!(vec, [1, 2, 3, 4]);
+    ///
+    /// let mut string = "Hello".to_string();
+    /// fill(&mut string, b", world!");
+    /// assert_eq!(string, "Hello, world!");
+    /// ```
+    fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<Self::Element>];
+
+    /// Sets the length of the collection, without dropping or initialising any elements.
+    ///
+    /// # Safety
+    ///
+    /// `len` must be at most the capacity of the collection, and all elements up to `len` must be
+    /// initialised. Any other invariants of the collection must also hold, e.g. the contents of a
+    /// `String` must be valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::SpareCapacityMut;
+    ///
+    /// let mut string = String::with_capacity(2);
+    /// let spare = SpareCapacityMut::spare_capacity_mut(&mut string);
+    /// spare[0].write(b'h');
+    /// spare[1].write(b'i');
+    /// // SAFETY: two bytes were initialised, and they are valid UTF-8.
+    /// unsafe { SpareCapacityMut::set_len(&mut string, 2) };
+    /// assert_eq!(string, "hi");
+    /// ```
+    unsafe fn set_len(&mut self, len: usize);
+}
+
+/// A trait for collections with a hard upper limit on their length.
+///
+/// Unlike [`Capacity`], which only states how much room there is before the next allocation, the
+/// maximum capacity is a limit which the collection can never grow past. This describes arrays,
+/// fixed-size buffers and arena-backed collections. Their capacity is never more than their
+/// maximum capacity, even when they store zero-sized types.
+///
+/// Obtaining the maximum capacity must take a constant amount of time and space.
+///
+/// [`Capacity`]: trait.Capacity.html
+pub trait MaxCapacity: Capacity {
+    /// Returns the maximum length of the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::MaxCapacity;
+    ///
+    /// assert_eq!(MaxCapacity::max_capacity(&[0u8; 16]), 16);
+    /// ```
+    fn max_capacity(&self) -> usize;
+
+    /// Returns how many more elements the collection can hold before reaching its maximum length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::MaxCapacity;
+    ///
+    /// fn has_room<C: MaxCapacity>(collection: &C, needed: usize) -> bool {
+    ///     collection.remaining_capacity() >= needed
+    /// }
+    ///
+    /// assert!(!has_room(&[0u8; 16], 1));
+    /// ```
+    fn remaining_capacity(&self) -> usize {
+        self.max_capacity().saturating_sub(self.len())
+    }
+}
+
+/// A trait for adding elements to a collection without growing past its maximum capacity.
+///
+/// Rather than reallocating, a full collection hands the element back in a [`CapacityExceeded`]
+/// error.
+///
+/// Pushing an element must take a constant amount of time and space.
+///
+/// [`CapacityExceeded`]: ../error/struct.CapacityExceeded.html
+pub trait BoundedPush<T>: MaxCapacity {
+    /// Adds an element to the collection, or returns it if the collection is full.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::{BoundedPush, Capacity, CapacityExceeded, Empty, Len, MaxCapacity};
+    ///
+    /// struct ArrayVec {
+    ///     items: [u32; 2],
+    ///     len: usize,
+    /// }
+    ///
+    /// impl Empty for ArrayVec {
+    ///     fn is_empty(&self) -> bool {
+    ///         self.len == 0
+    ///     }
+    /// }
+    /// impl Len for ArrayVec {
+    ///     fn len(&self) -> usize {
+    ///         self.len
+    ///     }
+    /// }
+    /// impl Capacity for ArrayVec {
+    ///     fn capacity(&self) -> usize {
+    ///         self.items.len()
+    ///     }
+    /// }
+    /// impl MaxCapacity for ArrayVec {
+    ///     fn max_capacity(&self) -> usize {
+    ///         self.items.len()
+    ///     }
+    /// }
+    /// impl BoundedPush<u32> for ArrayVec {
+    ///     fn try_push(&mut self, value: u32) -> Result<(), CapacityExceeded<u32>> {
+    ///         if self.remaining_capacity() == 0 {
+    ///             return Err(CapacityExceeded::new(value));
+    ///         }
+    ///         self.items[self.len] = value;
+    ///         self.len += 1;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut array = ArrayVec { items: [0; 2], len: 0 };
+    /// assert_eq!(array.try_push(1), Ok(()));
+    /// assert_eq!(array.try_push(2), Ok(()));
+    /// assert_eq!(array.try_push(3).unwrap_err().into_inner(), 3);
+    /// ```
+    fn try_push(&mut self, value: T) -> Result<(), CapacityExceeded<T>>;
+}
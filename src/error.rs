@@ -0,0 +1,175 @@
+//! Errors returned by the checked methods of this crate.
+//!
+//! Every method which can fail is also available in a form which panics, documenting when it does
+//! so. The checked forms return these errors instead, so that they can be handled or reported.
+//! They only depend on `core`, and implement `core::error::Error`.
+use core::error::Error;
+use core::fmt;
+
+/// An error returned when an index is past the end of a collection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OutOfBounds {
+    /// The index which was used.
+    pub index: usize,
+
+    /// The length of the collection.
+    pub len: usize,
+}
+
+impl fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "index {} is out of bounds for a collection of length {}", self.index, self.len)
+    }
+}
+
+impl Error for OutOfBounds {}
+
+/// An error returned when an index into a string doesn't lie on a character boundary.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NotCharBoundary {
+    /// The index which was used.
+    pub index: usize,
+}
+
+impl fmt::Display for NotCharBoundary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "index {} is not on a character boundary", self.index)
+    }
+}
+
+impl Error for NotCharBoundary {}
+
+/// An error returned when an index can't be used to split or truncate a collection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IndexError {
+    /// The index was past the end of the collection.
+    OutOfBounds(OutOfBounds),
+
+    /// The index was inside the collection, but didn't lie on a character boundary.
+    NotCharBoundary(NotCharBoundary),
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IndexError::OutOfBounds(err) => err.fmt(f),
+            IndexError::NotCharBoundary(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for IndexError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            IndexError::OutOfBounds(err) => Some(err),
+            IndexError::NotCharBoundary(err) => Some(err),
+        }
+    }
+}
+
+impl From<OutOfBounds> for IndexError {
+    fn from(err: OutOfBounds) -> Self {
+        IndexError::OutOfBounds(err)
+    }
+}
+
+impl From<NotCharBoundary> for IndexError {
+    fn from(err: NotCharBoundary) -> Self {
+        IndexError::NotCharBoundary(err)
+    }
+}
+
+/// An error returned when the capacity a collection needs can't be represented.
+///
+/// This happens when the number of elements doesn't fit in a `usize`, when the number of bytes
+/// they occupy is larger than `isize::MAX`, or when it's larger than a bounded collection allows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapacityOverflow;
+
+impl fmt::Display for CapacityOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("capacity overflow")
+    }
+}
+
+impl Error for CapacityOverflow {}
+
+/// An error returned when the allocator fails to provide memory for a collection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AllocError;
+
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("memory allocation failed")
+    }
+}
+
+impl Error for AllocError {}
+
+/// An error returned when capacity can't be reserved for a collection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ReserveError {
+    /// The requested capacity couldn't be represented.
+    CapacityOverflow(CapacityOverflow),
+
+    /// The allocator failed to provide the memory.
+    AllocError(AllocError),
+}
+
+impl fmt::Display for ReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReserveError::CapacityOverflow(err) => err.fmt(f),
+            ReserveError::AllocError(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for ReserveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReserveError::CapacityOverflow(err) => Some(err),
+            ReserveError::AllocError(err) => Some(err),
+        }
+    }
+}
+
+impl From<CapacityOverflow> for ReserveError {
+    fn from(err: CapacityOverflow) -> Self {
+        ReserveError::CapacityOverflow(err)
+    }
+}
+
+impl From<AllocError> for ReserveError {
+    fn from(err: AllocError) -> Self {
+        ReserveError::AllocError(err)
+    }
+}
+
+/// An error returned when adding an element would exceed a collection's maximum capacity.
+///
+/// The element which couldn't be added is returned inside the error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapacityExceeded<T> {
+    value: T,
+}
+
+impl<T> CapacityExceeded<T> {
+    /// Creates an error holding the element which couldn't be added.
+    pub fn new(value: T) -> Self {
+        CapacityExceeded { value }
+    }
+
+    /// Returns the element which couldn't be added.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> fmt::Display for CapacityExceeded<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("collection is at its maximum capacity")
+    }
+}
+
+impl<T: fmt::Debug> Error for CapacityExceeded<T> {}
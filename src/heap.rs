@@ -0,0 +1,112 @@
+//! Traits involving the heap memory owned by a collection.
+
+/// A trait for describing how many bytes of heap memory a value owns.
+///
+/// Unlike [`Capacity`], which counts elements, this counts bytes, so that the memory usage of
+/// different kinds of collections can be added up and compared. Only memory owned directly by the
+/// value is counted, not any memory owned by its elements; see [`DeepHeapSize`] for that.
+///
+/// For collections which don't expose their layout, like `BTreeMap` and `HashMap`, these are
+/// estimates based on the layout of the standard library's implementation. Values behind an `Rc`
+/// or `Arc` are counted in full by every handle which shares them.
+///
+/// Obtaining the heap size of a value must take a constant amount of time and space.
+///
+/// [`Capacity`]: ../capacity/trait.Capacity.html
+/// [`DeepHeapSize`]: trait.DeepHeapSize.html
+pub trait HeapSize {
+    /// Returns the number of bytes allocated on the heap, whether they are used or not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::HeapSize;
+    ///
+    /// let mut vec: Vec<u32> = Vec::with_capacity(10);
+    /// vec.push(1);
+    /// assert_eq!(vec.heap_reserved(), 40);
+    ///
+    /// let string = String::with_capacity(16);
+    /// assert_eq!(string.heap_reserved(), 16);
+    /// ```
+    fn heap_reserved(&self) -> usize;
+
+    /// Returns the number of bytes on the heap which are occupied by elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::HeapSize;
+    ///
+    /// let mut vec: Vec<u32> = Vec::with_capacity(10);
+    /// vec.push(1);
+    /// assert_eq!(vec.heap_used(), 4);
+    /// ```
+    fn heap_used(&self) -> usize;
+}
+
+/// A trait for describing how many bytes of heap memory a value owns, including its elements.
+///
+/// This is implemented for collections whose elements also implement `DeepHeapSize`, as well as
+/// for primitive types, which own no heap memory at all.
+///
+/// Like the sizes from [`HeapSize`], the totals saturate at `usize::MAX` instead of overflowing.
+///
+/// Obtaining the deep heap size of a collection must take a linear amount of time with respect to
+/// the total number of values it contains, and a constant amount of space.
+///
+/// # Examples
+///
+/// ```rust
+/// use len_trait::{DeepHeapSize, HeapSize};
+///
+/// struct Mapping;
+/// impl HeapSize for Mapping {
+///     fn heap_reserved(&self) -> usize {
+///         usize::MAX / 2
+///     }
+///     fn heap_used(&self) -> usize {
+///         0
+///     }
+/// }
+/// impl DeepHeapSize for Mapping {
+///     fn deep_heap_reserved(&self) -> usize {
+///         self.heap_reserved()
+///     }
+///     fn deep_heap_used(&self) -> usize {
+///         self.heap_used()
+///     }
+/// }
+///
+/// let mappings = vec![vec![Mapping, Mapping], vec![Mapping]];
+/// assert_eq!(mappings.deep_heap_reserved(), usize::MAX);
+/// ```
+///
+/// [`HeapSize`]: trait.HeapSize.html
+pub trait DeepHeapSize: HeapSize {
+    /// Returns the number of bytes allocated on the heap by this value and everything it owns,
+    /// whether they are used or not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::{DeepHeapSize, HeapSize};
+    ///
+    /// let nested = vec![String::with_capacity(8), String::with_capacity(16)];
+    /// assert_eq!(nested.deep_heap_reserved(), nested.heap_reserved() + 24);
+    /// ```
+    fn deep_heap_reserved(&self) -> usize;
+
+    /// Returns the number of bytes on the heap which are occupied by elements of this value and
+    /// everything it owns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::{DeepHeapSize, HeapSize};
+    ///
+    /// let nested = vec!["a".to_string(), "bc".to_string()];
+    /// assert_eq!(nested.deep_heap_used(), nested.heap_used() + 3);
+    /// ```
+    fn deep_heap_used(&self) -> usize;
+}
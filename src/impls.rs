@@ -114,6 +114,12 @@ macro_rules! do_impl {
                 $name::split_at(self, index)
             }
         }
+        impl<$($gen)*> $crate::index::SplitSlice<usize> for $impl_for {
+            type Slice = Self;
+            fn split_slice(&self, index: usize) -> (&Self, &Self) {
+                $name::split_at(self, index)
+            }
+        }
     };
     (SplitAtMut for $name:ident; $($gen:tt)*) => {
         do_impl!(SplitAtMut for $name, $name; $($gen)*);
@@ -125,17 +131,424 @@ macro_rules! do_impl {
                 $name::split_at_mut(self, index)
             }
         }
+        impl<$($gen)*> $crate::index::SplitSliceMut<usize> for $impl_for {
+            fn split_slice_mut(&mut self, index: usize) -> (&mut Self, &mut Self) {
+                $name::split_at_mut(self, index)
+            }
+        }
+    };
+    (deref SplitAt for $slice:ty, $impl_for:ty; $($gen:tt)*) => {
+        impl<$($gen)*> $crate::index::SplitAt<usize> for $impl_for {
+            fn split_at(&self, index: usize) -> (&$slice, &$slice) {
+                <$slice>::split_at(self, index)
+            }
+        }
+        do_impl!(deref SplitSlice for $slice, $impl_for; $($gen)*);
+    };
+    (deref SplitAtMut for $slice:ty, $impl_for:ty; $($gen:tt)*) => {
+        do_impl!(deref SplitAt for $slice, $impl_for; $($gen)*);
+        impl<$($gen)*> $crate::index::SplitAtMut<usize> for $impl_for {
+            fn split_at_mut(&mut self, index: usize) -> (&mut $slice, &mut $slice) {
+                <$slice>::split_at_mut(self, index)
+            }
+        }
+        do_impl!(deref SplitSliceMut for $slice, $impl_for; $($gen)*);
+    };
+    (deref SplitSlice for $slice:ty, $impl_for:ty; $($gen:tt)*) => {
+        impl<$($gen)*> $crate::index::SplitSlice<usize> for $impl_for {
+            type Slice = $slice;
+            fn split_slice(&self, index: usize) -> (&$slice, &$slice) {
+                <$slice>::split_at(self, index)
+            }
+        }
+    };
+    (deref SplitSliceMut for $slice:ty, $impl_for:ty; $($gen:tt)*) => {
+        impl<$($gen)*> $crate::index::SplitSliceMut<usize> for $impl_for {
+            fn split_slice_mut(&mut self, index: usize) -> (&mut $slice, &mut $slice) {
+                <$slice>::split_at_mut(self, index)
+            }
+        }
+    };
+}
+
+macro_rules! ends_impl {
+    ($impl_for:ty, $via:ty, $item:ty, $first:ident, $last:ident; $($gen:tt)*) => {
+        impl<$($gen)*> super::non_empty::Ends for $impl_for {
+            type Item = $item;
+            fn first_item(&self) -> Option<&$item> {
+                <$via>::$first(self)
+            }
+            fn last_item(&self) -> Option<&$item> {
+                <$via>::$last(self)
+            }
+        }
     };
 }
+
 type Slice<T> = [T];
 
 do_impl!(Len for Slice, [T]; T);
+ends_impl!([T], [T], T, first, last; T);
 do_impl!(SplitAtMut for Slice, [T]; T);
+impl<T> super::index::Slicable for [T] {}
+impl<T> super::index::GetRange for [T] {}
+impl<T> super::index::SplitStep for [T] {
+    fn step_forward(&self, index: usize, n: usize) -> Option<usize> {
+        assert!(index <= self.len(), "index out of bounds");
+        index.checked_add(n).filter(|&end| end <= self.len())
+    }
+    fn step_backward(&self, index: usize, n: usize) -> Option<usize> {
+        assert!(index <= self.len(), "index out of bounds");
+        index.checked_sub(n)
+    }
+}
+impl<T, const N: usize> super::len::Empty for [T; N] {
+    fn is_empty(&self) -> bool {
+        N == 0
+    }
+}
+impl<T, const N: usize> super::len::Len for [T; N] {
+    fn len(&self) -> usize {
+        N
+    }
+}
+impl<T, const N: usize> super::capacity::Capacity for [T; N] {
+    fn capacity(&self) -> usize {
+        N
+    }
+}
+impl<T, const N: usize> super::capacity::MaxCapacity for [T; N] {
+    fn max_capacity(&self) -> usize {
+        N
+    }
+}
+ends_impl!([T; N], [T], T, first, last; T, const N: usize);
+do_impl!(deref SplitAtMut for [T], [T; N]; T, const N: usize);
+impl<T, const N: usize> super::index::Slicable for [T; N] {}
+impl<T, const N: usize> super::index::GetRange for [T; N] {}
+
     type Str = str;
 
 
 do_impl!(Len for Str, str; );
 do_impl!(SplitAtMut for Str, str; );
+impl super::index::Slicable for str {}
+impl super::index::GetRange for str {
+    fn is_slice_boundary(&self, index: usize) -> bool {
+        str::is_char_boundary(self, index)
+    }
+}
+impl super::index::SplitStep for str {
+    fn step_forward(&self, index: usize, n: usize) -> Option<usize> {
+        let mut chars = self[index..].chars();
+        for _ in 0..n {
+            chars.next()?;
+        }
+        Some(self.len() - chars.as_str().len())
+    }
+    fn step_backward(&self, index: usize, n: usize) -> Option<usize> {
+        let mut chars = self[..index].chars();
+        for _ in 0..n {
+            chars.next_back()?;
+        }
+        Some(chars.as_str().len())
+    }
+}
+
+use super::len::LenIn;
+use super::unit;
+
+impl LenIn<unit::Bytes> for str {
+    fn len_in(&self) -> usize {
+        str::len(self)
+    }
+}
+impl LenIn<unit::Chars> for str {
+    fn len_in(&self) -> usize {
+        self.chars().count()
+    }
+}
+impl LenIn<unit::Utf16> for str {
+    fn len_in(&self) -> usize {
+        self.chars().map(char::len_utf16).sum()
+    }
+}
+#[cfg(feature = "unicode-segmentation")]
+impl LenIn<unit::Graphemes> for str {
+    fn len_in(&self) -> usize {
+        unicode_segmentation::UnicodeSegmentation::graphemes(self, true).count()
+    }
+}
+
+use super::string::{CharBoundary, CharBoundaryMut};
+
+impl CharBoundary for str {
+    type Slice = str;
+    fn is_char_boundary(&self, index: usize) -> bool {
+        str::is_char_boundary(self, index)
+    }
+    fn split_at_floor(&self, index: usize) -> (&str, &str) {
+        str::split_at(self, CharBoundary::floor_char_boundary(self, index))
+    }
+    fn split_at_ceil(&self, index: usize) -> (&str, &str) {
+        str::split_at(self, CharBoundary::ceil_char_boundary(self, index))
+    }
+}
+
+/// Implements `LenIn<$unit>` for a possibly non-Unicode string by measuring its lossy conversion.
+macro_rules! lossy_len_in {
+    ($name:ty; $($unit:ty),*) => {
+        $(
+            impl LenIn<$unit> for $name {
+                fn len_in(&self) -> usize {
+                    <str as LenIn<$unit>>::len_in(&self.to_string_lossy())
+                }
+            }
+        )*
+    };
+}
+
+use std::alloc::Layout;
+use std::mem;
+use super::heap::{DeepHeapSize, HeapSize};
+
+/// Implements `HeapSize` and `DeepHeapSize` for types which own no heap memory.
+macro_rules! no_heap_impl {
+    ($($impl_for:ty),*) => {
+        $(
+            impl HeapSize for $impl_for {
+                fn heap_reserved(&self) -> usize {
+                    0
+                }
+                fn heap_used(&self) -> usize {
+                    0
+                }
+            }
+            impl DeepHeapSize for $impl_for {
+                fn deep_heap_reserved(&self) -> usize {
+                    0
+                }
+                fn deep_heap_used(&self) -> usize {
+                    0
+                }
+            }
+        )*
+    };
+}
+no_heap_impl!(bool, char, f32, f64, (), str);
+no_heap_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Implements `HeapSize` for a collection which stores its elements in one buffer.
+macro_rules! buffer_heap_impl {
+    ($impl_for:ty, $elem:ty; $($gen:tt)*) => {
+        impl<$($gen)*> HeapSize for $impl_for {
+            fn heap_reserved(&self) -> usize {
+                self.capacity().saturating_mul(mem::size_of::<$elem>())
+            }
+            fn heap_used(&self) -> usize {
+                self.len().saturating_mul(mem::size_of::<$elem>())
+            }
+        }
+    };
+}
+
+/// Implements `DeepHeapSize` for a collection by adding up the deep heap sizes of its elements.
+macro_rules! deep_heap_impl {
+    ($impl_for:ty; $($gen:tt)*) => {
+        impl<$($gen)*> DeepHeapSize for $impl_for {
+            fn deep_heap_reserved(&self) -> usize {
+                self.iter().fold(self.heap_reserved(), |size, elem| {
+                    size.saturating_add(elem.deep_heap_reserved())
+                })
+            }
+            fn deep_heap_used(&self) -> usize {
+                self.iter().fold(self.heap_used(), |size, elem| {
+                    size.saturating_add(elem.deep_heap_used())
+                })
+            }
+        }
+    };
+}
+
+impl<T> HeapSize for [T] {
+    fn heap_reserved(&self) -> usize {
+        0
+    }
+    fn heap_used(&self) -> usize {
+        0
+    }
+}
+deep_heap_impl!([T]; T: DeepHeapSize);
+
+impl<T: HeapSize> HeapSize for Option<T> {
+    fn heap_reserved(&self) -> usize {
+        self.as_ref().map_or(0, T::heap_reserved)
+    }
+    fn heap_used(&self) -> usize {
+        self.as_ref().map_or(0, T::heap_used)
+    }
+}
+impl<T: DeepHeapSize> DeepHeapSize for Option<T> {
+    fn deep_heap_reserved(&self) -> usize {
+        self.as_ref().map_or(0, T::deep_heap_reserved)
+    }
+    fn deep_heap_used(&self) -> usize {
+        self.as_ref().map_or(0, T::deep_heap_used)
+    }
+}
+
+/// Returns the size of the allocation behind an `Rc` or `Arc`, including its reference counts.
+fn counted_heap_size<T: ?Sized>(value: &T) -> usize {
+    Layout::new::<[usize; 2]>()
+        .extend(Layout::for_value(value))
+        .map(|(layout, _)| layout.pad_to_align().size())
+        .unwrap_or(usize::MAX)
+}
+
+/// The maximum number of elements in a node of a `BTreeMap`.
+const BTREE_CAPACITY: usize = 11;
+
+/// The number of elements assumed to be in each node of a `BTreeMap`, when estimating its size.
+///
+/// Nodes are split once they're full, and are at least half full otherwise. Random insertions
+/// leave them about 70% full on average, while maps collected from iterators have fuller nodes, so
+/// this errs on the side of overestimating.
+const BTREE_AVERAGE_LEN: usize = 8;
+
+/// Estimates the number of bytes allocated by a `BTreeMap<K, V>` of the given length.
+fn btree_heap_size<K, V>(len: usize) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    // Leaves store a parent pointer, their index in the parent, their length, and their elements.
+    // Internal nodes additionally store pointers to their children.
+    let leaf = mem::size_of::<(usize, u16, u16, [K; BTREE_CAPACITY], [V; BTREE_CAPACITY])>();
+    let internal = leaf + mem::size_of::<[usize; BTREE_CAPACITY + 1]>();
+    let leaves = len.div_ceil(BTREE_AVERAGE_LEN);
+    let internals = (leaves - 1).div_ceil(BTREE_AVERAGE_LEN);
+    leaves.saturating_mul(leaf).saturating_add(internals.saturating_mul(internal))
+}
+
+/// The number of control bytes which are stored past the end of a `HashMap`'s table.
+const HASH_GROUP_WIDTH: usize = 16;
+
+/// Estimates the number of bytes allocated by a `HashMap` with the given capacity, where each
+/// entry is a `T`.
+fn hash_heap_size<T>(capacity: usize) -> usize {
+    if capacity == 0 {
+        return 0;
+    }
+    // Tables have a power-of-two number of buckets, and are at most 7/8 full once they have at
+    // least eight. Every bucket has one control byte, in addition to its entry.
+    let buckets = if capacity < 8 { capacity + 1 } else { capacity / 7 * 8 };
+    buckets.saturating_mul(mem::size_of::<T>() + 1).saturating_add(HASH_GROUP_WIDTH)
+}
+
+use std::ops::{Range, RangeInclusive};
+
+macro_rules! range_impl {
+    ($($int:ty),*) => {
+        $(
+            impl super::iter::RangeLen for Range<$int> {
+                fn range_len(&self) -> Option<usize> {
+                    if self.is_empty() {
+                        return Some(0);
+                    }
+                    usize::try_from(self.end.abs_diff(self.start)).ok()
+                }
+            }
+            impl super::iter::RangeLen for RangeInclusive<$int> {
+                fn range_len(&self) -> Option<usize> {
+                    if self.is_empty() {
+                        return Some(0);
+                    }
+                    self.end().abs_diff(*self.start())
+                        .checked_add(1)
+                        .and_then(|len| usize::try_from(len).ok())
+                }
+            }
+            no_heap_impl!(Range<$int>, RangeInclusive<$int>);
+        )*
+    };
+}
+range_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+use std::collections::TryReserveError;
+use super::error::{AllocError, CapacityOverflow, IndexError, NotCharBoundary, OutOfBounds};
+use super::error::ReserveError;
+
+/// Converts an error from the standard library's `try_reserve`, which doesn't expose its kind, by
+/// checking whether the requested capacity can be represented.
+fn reserve_error<T>(len: usize, additional: usize, _: TryReserveError) -> ReserveError {
+    match len.checked_add(additional).map(Layout::array::<T>) {
+        Some(Ok(_)) => AllocError.into(),
+        _ => CapacityOverflow.into(),
+    }
+}
+
+/// Implements `WithCapacity` and `CapacityMut` for hashed collections with any hasher.
+///
+/// The inherent `with_capacity` only exists for the default hasher, so this builds the hasher
+/// from its `Default` instead.
+macro_rules! hash_capacity_impl {
+    ($impl_for:ty; $($gen:tt)*) => {
+        impl<$($gen)*> super::capacity::WithCapacity for $impl_for
+        where
+            S: ::std::hash::BuildHasher + Default,
+        {
+            fn with_capacity(capacity: usize) -> Self {
+                <$impl_for>::with_capacity_and_hasher(capacity, S::default())
+            }
+        }
+        impl<$($gen)*> super::capacity::CapacityMut for $impl_for
+        where
+            S: ::std::hash::BuildHasher + Default,
+        {
+            fn reserve(&mut self, additional: usize) {
+                <$impl_for>::reserve(self, additional)
+            }
+            fn shrink_to_fit(&mut self) {
+                <$impl_for>::shrink_to_fit(self)
+            }
+        }
+    };
+}
+
+macro_rules! try_reserve_impl {
+    ($impl_for:ty, $elem:ty; $($gen:tt)*) => {
+        impl<$($gen)*> super::capacity::TryCapacityMut for $impl_for {
+            fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+                let len = self.len();
+                <$impl_for>::try_reserve(self, additional)
+                    .map_err(|err| reserve_error::<$elem>(len, additional, err))
+            }
+            fn try_reserve_exact(&mut self, additional: usize) -> Result<(), ReserveError> {
+                let len = self.len();
+                <$impl_for>::try_reserve_exact(self, additional)
+                    .map_err(|err| reserve_error::<$elem>(len, additional, err))
+            }
+        }
+    };
+    (inexact $impl_for:ty, $elem:ty; $($gen:tt)*) => {
+        impl<$($gen)*> super::capacity::TryCapacityMut for $impl_for {
+            fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+                let len = self.len();
+                <$impl_for>::try_reserve(self, additional)
+                    .map_err(|err| reserve_error::<$elem>(len, additional, err))
+            }
+        }
+    };
+}
+
+/// Checks that an index is no greater than the length of a collection.
+fn check_bounds(index: usize, len: usize) -> Result<(), IndexError> {
+    if index > len {
+        Err(OutOfBounds { index, len }.into())
+    } else {
+        Ok(())
+    }
+}
+
 use std::boxed::Box;
              use std::rc::Rc;
              use std::sync::Arc;
@@ -143,6 +556,8 @@ use std::boxed::Box;
     
     
         use self::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+        use super::segment::{MakeContiguous, Segments, SegmentsMut};
+        use super::segment::{SplitSegments, SplitSegmentsMut};
 
 
         impl<T: ?Sized + super::len::Empty> super::len::Empty for Box<T> {
@@ -155,11 +570,45 @@ use std::boxed::Box;
                 T::len(self)
             }
         }
+        impl<T: ?Sized + super::non_empty::Ends> super::non_empty::Ends for Box<T> {
+            type Item = T::Item;
+            fn first_item(&self) -> Option<&T::Item> {
+                T::first_item(self)
+            }
+            fn last_item(&self) -> Option<&T::Item> {
+                T::last_item(self)
+            }
+        }
         impl<T: ?Sized + super::capacity::Capacity> super::capacity::Capacity for Box<T> {
             fn capacity(&self) -> usize {
                 T::capacity(self)
             }
         }
+        impl<U, T: ?Sized + LenIn<U>> LenIn<U> for Box<T> {
+            fn len_in(&self) -> usize {
+                T::len_in(self)
+            }
+        }
+        do_impl!(deref SplitSlice for [T], Box<[T]>; T);
+        do_impl!(deref SplitSliceMut for [T], Box<[T]>; T);
+        do_impl!(deref SplitSlice for str, Box<str>; );
+        do_impl!(deref SplitSliceMut for str, Box<str>; );
+        impl<T: ?Sized + HeapSize> HeapSize for Box<T> {
+            fn heap_reserved(&self) -> usize {
+                mem::size_of_val::<T>(self).saturating_add(T::heap_reserved(self))
+            }
+            fn heap_used(&self) -> usize {
+                mem::size_of_val::<T>(self).saturating_add(T::heap_used(self))
+            }
+        }
+        impl<T: ?Sized + DeepHeapSize> DeepHeapSize for Box<T> {
+            fn deep_heap_reserved(&self) -> usize {
+                mem::size_of_val::<T>(self).saturating_add(T::deep_heap_reserved(self))
+            }
+            fn deep_heap_used(&self) -> usize {
+                mem::size_of_val::<T>(self).saturating_add(T::deep_heap_used(self))
+            }
+        }
 
         impl<T: ?Sized + super::len::Empty> super::len::Empty for Rc<T> {
             fn is_empty(&self) -> bool {
@@ -171,11 +620,43 @@ use std::boxed::Box;
                 T::len(self)
             }
         }
+        impl<T: ?Sized + super::non_empty::Ends> super::non_empty::Ends for Rc<T> {
+            type Item = T::Item;
+            fn first_item(&self) -> Option<&T::Item> {
+                T::first_item(self)
+            }
+            fn last_item(&self) -> Option<&T::Item> {
+                T::last_item(self)
+            }
+        }
         impl<T: ?Sized + super::capacity::Capacity> super::capacity::Capacity for Rc<T> {
             fn capacity(&self) -> usize {
                 T::capacity(self)
             }
         }
+        impl<U, T: ?Sized + LenIn<U>> LenIn<U> for Rc<T> {
+            fn len_in(&self) -> usize {
+                T::len_in(self)
+            }
+        }
+        do_impl!(deref SplitSlice for [T], Rc<[T]>; T);
+        do_impl!(deref SplitSlice for str, Rc<str>; );
+        impl<T: ?Sized + HeapSize> HeapSize for Rc<T> {
+            fn heap_reserved(&self) -> usize {
+                counted_heap_size::<T>(self).saturating_add(T::heap_reserved(self))
+            }
+            fn heap_used(&self) -> usize {
+                mem::size_of_val::<T>(self).saturating_add(T::heap_used(self))
+            }
+        }
+        impl<T: ?Sized + DeepHeapSize> DeepHeapSize for Rc<T> {
+            fn deep_heap_reserved(&self) -> usize {
+                counted_heap_size::<T>(self).saturating_add(T::deep_heap_reserved(self))
+            }
+            fn deep_heap_used(&self) -> usize {
+                mem::size_of_val::<T>(self).saturating_add(T::deep_heap_used(self))
+            }
+        }
 
         impl<T: ?Sized + super::len::Empty> super::len::Empty for Arc<T> {
             fn is_empty(&self) -> bool {
@@ -187,37 +668,262 @@ use std::boxed::Box;
                 T::len(self)
             }
         }
+        impl<T: ?Sized + super::non_empty::Ends> super::non_empty::Ends for Arc<T> {
+            type Item = T::Item;
+            fn first_item(&self) -> Option<&T::Item> {
+                T::first_item(self)
+            }
+            fn last_item(&self) -> Option<&T::Item> {
+                T::last_item(self)
+            }
+        }
         impl<T: ?Sized + super::capacity::Capacity> super::capacity::Capacity for Arc<T> {
             fn capacity(&self) -> usize {
                 T::capacity(self)
             }
         }
+        impl<U, T: ?Sized + LenIn<U>> LenIn<U> for Arc<T> {
+            fn len_in(&self) -> usize {
+                T::len_in(self)
+            }
+        }
+        do_impl!(deref SplitSlice for [T], Arc<[T]>; T);
+        do_impl!(deref SplitSlice for str, Arc<str>; );
+        impl<T: ?Sized + HeapSize> HeapSize for Arc<T> {
+            fn heap_reserved(&self) -> usize {
+                counted_heap_size::<T>(self).saturating_add(T::heap_reserved(self))
+            }
+            fn heap_used(&self) -> usize {
+                mem::size_of_val::<T>(self).saturating_add(T::heap_used(self))
+            }
+        }
+        impl<T: ?Sized + DeepHeapSize> DeepHeapSize for Arc<T> {
+            fn deep_heap_reserved(&self) -> usize {
+                counted_heap_size::<T>(self).saturating_add(T::deep_heap_reserved(self))
+            }
+            fn deep_heap_used(&self) -> usize {
+                mem::size_of_val::<T>(self).saturating_add(T::deep_heap_used(self))
+            }
+        }
+
+        // A guard is only held while the collection is locked, so its length can't change.
+        macro_rules! guard_impl {
+            ($($guard:ident),*) => {
+                $(
+                    impl<T: ?Sized + super::len::Empty> super::len::Empty for $guard<'_, T> {
+                        fn is_empty(&self) -> bool {
+                            T::is_empty(self)
+                        }
+                    }
+                    impl<T: ?Sized + super::len::Len> super::len::Len for $guard<'_, T> {
+                        fn len(&self) -> usize {
+                            T::len(self)
+                        }
+                    }
+                )*
+            };
+        }
+        use std::sync::{MutexGuard, RwLockReadGuard, RwLockWriteGuard};
+        guard_impl!(MutexGuard, RwLockReadGuard, RwLockWriteGuard);
 
 
         do_impl!(Len for BTreeMap, BTreeMap<K, V>; K: Ord, V);
         do_impl!(Clear for BTreeMap, BTreeMap<K, V>; K: Ord, V);
+        impl<K: Ord, V> HeapSize for BTreeMap<K, V> {
+            fn heap_reserved(&self) -> usize {
+                btree_heap_size::<K, V>(self.len())
+            }
+            fn heap_used(&self) -> usize {
+                self.len().saturating_mul(mem::size_of::<K>() + mem::size_of::<V>())
+            }
+        }
+        impl<K: Ord + DeepHeapSize, V: DeepHeapSize> DeepHeapSize for BTreeMap<K, V> {
+            fn deep_heap_reserved(&self) -> usize {
+                self.iter().fold(self.heap_reserved(), |size, (k, v)| {
+                    size.saturating_add(k.deep_heap_reserved())
+                        .saturating_add(v.deep_heap_reserved())
+                })
+            }
+            fn deep_heap_used(&self) -> usize {
+                self.iter().fold(self.heap_used(), |size, (k, v)| {
+                    size.saturating_add(k.deep_heap_used())
+                        .saturating_add(v.deep_heap_used())
+                })
+            }
+        }
 
         do_impl!(Len for BTreeSet, BTreeSet<T>; T: Ord);
+        ends_impl!(BTreeSet<T>, BTreeSet<T>, T, first, last; T: Ord);
         do_impl!(Clear for BTreeSet, BTreeSet<T>; T: Ord);
+        impl<T: Ord> HeapSize for BTreeSet<T> {
+            fn heap_reserved(&self) -> usize {
+                btree_heap_size::<T, ()>(self.len())
+            }
+            fn heap_used(&self) -> usize {
+                self.len().saturating_mul(mem::size_of::<T>())
+            }
+        }
+        deep_heap_impl!(BTreeSet<T>; T: Ord + DeepHeapSize);
 
         do_impl!(Len for BinaryHeap, BinaryHeap<T>; T: Ord);
         do_impl!(Clear for BinaryHeap, BinaryHeap<T>; T: Ord);
         do_impl!(CapacityMut for BinaryHeap, BinaryHeap<T>; T: Ord);
+        try_reserve_impl!(BinaryHeap<T>, T; T: Ord);
+        buffer_heap_impl!(BinaryHeap<T>, T; T: Ord);
+        deep_heap_impl!(BinaryHeap<T>; T: Ord + DeepHeapSize);
 
         do_impl!(Len for LinkedList, LinkedList<T>; T);
+        ends_impl!(LinkedList<T>, LinkedList<T>, T, front, back; T);
         do_impl!(Clear for LinkedList, LinkedList<T>; T);
+        impl<T> HeapSize for LinkedList<T> {
+            fn heap_reserved(&self) -> usize {
+                // Every node stores pointers to its neighbours, along with its element.
+                self.len().saturating_mul(mem::size_of::<(usize, usize, T)>())
+            }
+            fn heap_used(&self) -> usize {
+                self.len().saturating_mul(mem::size_of::<T>())
+            }
+        }
+        deep_heap_impl!(LinkedList<T>; T: DeepHeapSize);
 
         do_impl!(Len for str, String; );
         do_impl!(LenMut for String; );
+        impl super::len::TryLenMut for String {
+            fn check_boundary(&self, index: usize) -> Result<(), IndexError> {
+                check_bounds(index, self.len())?;
+                if self.is_char_boundary(index) {
+                    Ok(())
+                } else {
+                    Err(NotCharBoundary { index }.into())
+                }
+            }
+        }
         do_impl!(CapacityMut for String; );
+        try_reserve_impl!(String, u8; );
+        do_impl!(deref SplitAtMut for str, String; );
+        impl super::index::Slicable for String {}
+        impl super::index::GetRange for String {
+            fn is_slice_boundary(&self, index: usize) -> bool {
+                str::is_char_boundary(self, index)
+            }
+        }
+        impl super::capacity::SpareCapacityMut for String {
+            type Element = u8;
+            fn spare_capacity_mut(&mut self) -> &mut [mem::MaybeUninit<u8>] {
+                // SAFETY: the spare capacity isn't part of the string, so writing anything to it
+                // can't make the string invalid UTF-8.
+                unsafe { self.as_mut_vec() }.spare_capacity_mut()
+            }
+            unsafe fn set_len(&mut self, len: usize) {
+                // SAFETY: the caller ensures the bytes are initialised and valid UTF-8.
+                unsafe { self.as_mut_vec().set_len(len) }
+            }
+        }
+        buffer_heap_impl!(String, u8; );
+        impl DeepHeapSize for String {
+            fn deep_heap_reserved(&self) -> usize {
+                self.heap_reserved()
+            }
+            fn deep_heap_used(&self) -> usize {
+                self.heap_used()
+            }
+        }
+        impl<U> LenIn<U> for String where str: LenIn<U> {
+            fn len_in(&self) -> usize {
+                str::len_in(self)
+            }
+        }
+        impl CharBoundary for String {
+            type Slice = str;
+            fn is_char_boundary(&self, index: usize) -> bool {
+                str::is_char_boundary(self, index)
+            }
+            fn split_at_floor(&self, index: usize) -> (&str, &str) {
+                CharBoundary::split_at_floor(self.as_str(), index)
+            }
+            fn split_at_ceil(&self, index: usize) -> (&str, &str) {
+                CharBoundary::split_at_ceil(self.as_str(), index)
+            }
+        }
+        impl CharBoundaryMut for String {
+            fn truncate_floor(&mut self, len: usize) {
+                let len = CharBoundary::floor_char_boundary(self, len);
+                String::truncate(self, len)
+            }
+        }
 
         do_impl!(Len for Self, Vec<T>; T);
+        ends_impl!(Vec<T>, [T], T, first, last; T);
         do_impl!(LenMut for Vec, Vec<T>; T);
+        impl<T> super::len::TryLenMut for Vec<T> {
+            fn check_boundary(&self, index: usize) -> Result<(), IndexError> {
+                check_bounds(index, self.len())
+            }
+        }
         do_impl!(CapacityMut for Vec, Vec<T>; T);
+        try_reserve_impl!(Vec<T>, T; T);
+        do_impl!(deref SplitAtMut for [T], Vec<T>; T);
+        impl<T> super::index::Slicable for Vec<T> {}
+        impl<T> super::index::GetRange for Vec<T> {}
+        impl<T> super::capacity::SpareCapacityMut for Vec<T> {
+            type Element = T;
+            fn spare_capacity_mut(&mut self) -> &mut [mem::MaybeUninit<T>] {
+                Vec::spare_capacity_mut(self)
+            }
+            unsafe fn set_len(&mut self, len: usize) {
+                // SAFETY: the caller upholds the same contract as `Vec::set_len`.
+                unsafe { Vec::set_len(self, len) }
+            }
+        }
+        buffer_heap_impl!(Vec<T>, T; T);
+        deep_heap_impl!(Vec<T>; T: DeepHeapSize);
+        impl<T> SplitSegments for Vec<T> {
+            type Slice = [T];
+            fn as_segments(&self) -> Segments<'_, [T]> {
+                Segments::new(self, &[])
+            }
+        }
+        impl<T> SplitSegmentsMut for Vec<T> {
+            fn as_segments_mut(&mut self) -> SegmentsMut<'_, [T]> {
+                SegmentsMut::new(self, &mut [])
+            }
+        }
+        impl<T> MakeContiguous for Vec<T> {
+            fn make_contiguous(&mut self) -> &mut [T] {
+                self
+            }
+        }
 
         do_impl!(Len for VecDeque, VecDeque<T>; T);
+        ends_impl!(VecDeque<T>, VecDeque<T>, T, front, back; T);
         do_impl!(LenMut for VecDeque, VecDeque<T>; T);
+        impl<T> super::len::TryLenMut for VecDeque<T> {
+            fn check_boundary(&self, index: usize) -> Result<(), IndexError> {
+                check_bounds(index, self.len())
+            }
+        }
         do_impl!(CapacityMut for VecDeque, VecDeque<T>; T);
+        try_reserve_impl!(VecDeque<T>, T; T);
+        buffer_heap_impl!(VecDeque<T>, T; T);
+        deep_heap_impl!(VecDeque<T>; T: DeepHeapSize);
+        impl<T> SplitSegments for VecDeque<T> {
+            type Slice = [T];
+            fn as_segments(&self) -> Segments<'_, [T]> {
+                let (front, back) = self.as_slices();
+                Segments::new(front, back)
+            }
+        }
+        impl<T> SplitSegmentsMut for VecDeque<T> {
+            fn as_segments_mut(&mut self) -> SegmentsMut<'_, [T]> {
+                let (front, back) = self.as_mut_slices();
+                SegmentsMut::new(front, back)
+            }
+        }
+        impl<T> MakeContiguous for VecDeque<T> {
+            fn make_contiguous(&mut self) -> &mut [T] {
+                VecDeque::make_contiguous(self)
+            }
+        }
 
    use std::ffi;
 
@@ -243,6 +949,36 @@ use std::boxed::Box;
                 self.as_bytes().len()
             }
         }
+        impl LenIn<unit::Bytes> for ffi::CStr {
+            fn len_in(&self) -> usize {
+                self.to_bytes().len()
+            }
+        }
+        lossy_len_in!(ffi::CStr; unit::Chars, unit::Utf16);
+        #[cfg(feature = "unicode-segmentation")]
+        lossy_len_in!(ffi::CStr; unit::Graphemes);
+        impl<U> LenIn<U> for ffi::CString where ffi::CStr: LenIn<U> {
+            fn len_in(&self) -> usize {
+                ffi::CStr::len_in(self)
+            }
+        }
+        no_heap_impl!(ffi::CStr);
+        impl HeapSize for ffi::CString {
+            fn heap_reserved(&self) -> usize {
+                self.as_bytes_with_nul().len()
+            }
+            fn heap_used(&self) -> usize {
+                self.as_bytes_with_nul().len()
+            }
+        }
+        impl DeepHeapSize for ffi::CString {
+            fn deep_heap_reserved(&self) -> usize {
+                self.heap_reserved()
+            }
+            fn deep_heap_used(&self) -> usize {
+                self.heap_used()
+            }
+        }
         // TODO: Clear for CString
         // TODO: LenMut for CString
 
@@ -252,17 +988,257 @@ use std::boxed::Box;
         do_impl!(Clear for OsString; );
         // TODO: LenMut for OsString
         do_impl!(CapacityMut for OsString; );
+        try_reserve_impl!(OsString, u8; );
+        impl LenIn<unit::Bytes> for OsStr {
+            fn len_in(&self) -> usize {
+                OsStr::len(self)
+            }
+        }
+        no_heap_impl!(OsStr);
+        impl HeapSize for OsString {
+            fn heap_reserved(&self) -> usize {
+                self.capacity()
+            }
+            fn heap_used(&self) -> usize {
+                self.len()
+            }
+        }
+        impl DeepHeapSize for OsString {
+            fn deep_heap_reserved(&self) -> usize {
+                self.heap_reserved()
+            }
+            fn deep_heap_used(&self) -> usize {
+                self.heap_used()
+            }
+        }
+        lossy_len_in!(OsStr; unit::Chars, unit::Utf16);
+        #[cfg(feature = "unicode-segmentation")]
+        lossy_len_in!(OsStr; unit::Graphemes);
+        impl<U> LenIn<U> for OsString where OsStr: LenIn<U> {
+            fn len_in(&self) -> usize {
+                OsStr::len_in(self)
+            }
+        }
+
+        impl CharBoundary for OsStr {
+            type Slice = OsStr;
+            fn is_char_boundary(&self, index: usize) -> bool {
+                // `OsStr::from_encoded_bytes_unchecked` only allows splitting immediately before
+                // or after a valid, non-empty UTF-8 substring.
+                let bytes = self.as_encoded_bytes();
+                if index == 0 || index == bytes.len() {
+                    return true;
+                } else if index > bytes.len() {
+                    return false;
+                }
+                let after = &bytes[index..bytes.len().min(index + 4)];
+                let starts_char = match str::from_utf8(after) {
+                    Ok(_) => true,
+                    Err(err) => err.valid_up_to() > 0,
+                };
+                starts_char ||
+                    (1..=index.min(4)).any(|n| str::from_utf8(&bytes[index - n..index]).is_ok())
+            }
+            fn split_at_floor(&self, index: usize) -> (&OsStr, &OsStr) {
+                let index = CharBoundary::floor_char_boundary(self, index);
+                let (left, right) = self.as_encoded_bytes().split_at(index);
+                // SAFETY: both halves come from an `OsStr` split at a boundary.
+                unsafe {
+                    (OsStr::from_encoded_bytes_unchecked(left), OsStr::from_encoded_bytes_unchecked(right))
+                }
+            }
+            fn split_at_ceil(&self, index: usize) -> (&OsStr, &OsStr) {
+                let index = CharBoundary::ceil_char_boundary(self, index);
+                let (left, right) = self.as_encoded_bytes().split_at(index);
+                // SAFETY: both halves come from an `OsStr` split at a boundary.
+                unsafe {
+                    (OsStr::from_encoded_bytes_unchecked(left), OsStr::from_encoded_bytes_unchecked(right))
+                }
+            }
+        }
+        impl CharBoundary for OsString {
+            type Slice = OsStr;
+            fn is_char_boundary(&self, index: usize) -> bool {
+                CharBoundary::is_char_boundary(self.as_os_str(), index)
+            }
+            fn split_at_floor(&self, index: usize) -> (&OsStr, &OsStr) {
+                CharBoundary::split_at_floor(self.as_os_str(), index)
+            }
+            fn split_at_ceil(&self, index: usize) -> (&OsStr, &OsStr) {
+                CharBoundary::split_at_ceil(self.as_os_str(), index)
+            }
+        }
+        impl CharBoundaryMut for OsString {
+            fn truncate_floor(&mut self, len: usize) {
+                let len = CharBoundary::floor_char_boundary(self, len);
+                let mut bytes = std::mem::take(self).into_encoded_bytes();
+                bytes.truncate(len);
+                // SAFETY: the bytes come from an `OsString` truncated at a boundary.
+                *self = unsafe { OsString::from_encoded_bytes_unchecked(bytes) };
+            }
+        }
 
-        use std::hash::Hash;
+        use std::hash::{BuildHasher, Hash};
         use std::collections::HashMap;
-        do_impl!(Len for HashMap, HashMap<K, V>; K: Eq + Hash, V);
-        do_impl!(Clear for HashMap, HashMap<K, V>; K: Eq + Hash, V);
-        do_impl!(inexact CapacityMut for HashMap, HashMap<K, V>; K: Eq + Hash, V);
+        do_impl!(Len for HashMap, HashMap<K, V, S>; K: Eq + Hash, V, S);
+        do_impl!(Clear for HashMap, HashMap<K, V, S>; K: Eq + Hash, V, S);
+        do_impl!(Capacity for HashMap, HashMap<K, V, S>; K: Eq + Hash, V, S);
+        hash_capacity_impl!(HashMap<K, V, S>; K: Eq + Hash, V, S);
+        try_reserve_impl!(inexact HashMap<K, V, S>, (K, V);
+                          K: Eq + Hash, V, S: BuildHasher + Default);
+        impl<K: Eq + Hash, V, S> HeapSize for HashMap<K, V, S> {
+            fn heap_reserved(&self) -> usize {
+                hash_heap_size::<(K, V)>(self.capacity())
+            }
+            fn heap_used(&self) -> usize {
+                self.len().saturating_mul(mem::size_of::<(K, V)>())
+            }
+        }
+        impl<K, V, S> DeepHeapSize for HashMap<K, V, S>
+        where
+            K: Eq + Hash + DeepHeapSize,
+            V: DeepHeapSize,
+        {
+            fn deep_heap_reserved(&self) -> usize {
+                self.iter().fold(self.heap_reserved(), |size, (k, v)| {
+                    size.saturating_add(k.deep_heap_reserved())
+                        .saturating_add(v.deep_heap_reserved())
+                })
+            }
+            fn deep_heap_used(&self) -> usize {
+                self.iter().fold(self.heap_used(), |size, (k, v)| {
+                    size.saturating_add(k.deep_heap_used())
+                        .saturating_add(v.deep_heap_used())
+                })
+            }
+        }
 
         use std::collections::HashSet;
-        do_impl!(Len for HashSet, HashSet<T>; T: Eq + Hash);
-        do_impl!(Clear for HashSet, HashSet<T>; T: Eq + Hash);
-        do_impl!(inexact CapacityMut for HashSet, HashSet<T>; T: Eq + Hash);
+        do_impl!(Len for HashSet, HashSet<T, S>; T: Eq + Hash, S);
+        do_impl!(Clear for HashSet, HashSet<T, S>; T: Eq + Hash, S);
+        do_impl!(Capacity for HashSet, HashSet<T, S>; T: Eq + Hash, S);
+        hash_capacity_impl!(HashSet<T, S>; T: Eq + Hash, S);
+        try_reserve_impl!(inexact HashSet<T, S>, T; T: Eq + Hash, S: BuildHasher + Default);
+        impl<T: Eq + Hash, S> HeapSize for HashSet<T, S> {
+            fn heap_reserved(&self) -> usize {
+                hash_heap_size::<T>(self.capacity())
+            }
+            fn heap_used(&self) -> usize {
+                self.len().saturating_mul(mem::size_of::<T>())
+            }
+        }
+        deep_heap_impl!(HashSet<T, S>; T: Eq + Hash + DeepHeapSize, S);
+
+#[cfg(feature = "serde")]
+macro_rules! serde_impl {
+    ($kind:ident for $impl_for:ty; $($gen:tt)*) => {
+        impl<'de, $($gen)*> super::serde::DeserializeWithCapacity<'de> for $impl_for {
+            fn deserialize_with_capacity<D>(deserializer: D, max_bytes: usize)
+                -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                super::serde::$kind(deserializer, max_bytes)
+            }
+        }
+    };
+}
+#[cfg(feature = "serde")]
+serde_impl!(deserialize_seq for BinaryHeap<T>; T: Ord + ::serde::Deserialize<'de>);
+#[cfg(feature = "serde")]
+serde_impl!(deserialize_seq for Vec<T>; T: ::serde::Deserialize<'de>);
+#[cfg(feature = "serde")]
+serde_impl!(deserialize_seq for VecDeque<T>; T: ::serde::Deserialize<'de>);
+#[cfg(feature = "serde")]
+serde_impl!(deserialize_seq for HashSet<T, S>;
+            T: Eq + Hash + ::serde::Deserialize<'de>, S: BuildHasher + Default);
+#[cfg(feature = "serde")]
+serde_impl!(deserialize_map for HashMap<K, V, S>;
+            K: Eq + Hash + ::serde::Deserialize<'de>, V: ::serde::Deserialize<'de>,
+            S: BuildHasher + Default);
+
+#[cfg(feature = "std")]
+mod io {
+    use std::io::{BufReader, BufWriter, Cursor, Write};
+
+    use super::super::capacity::Capacity;
+    use super::super::io::RemainingLen;
+    use super::super::len::{Clear, Empty, Len, LenMut};
+
+    /// Returns the position of a cursor, saturating if it doesn't fit in a `usize`.
+    fn position<T>(cursor: &Cursor<T>) -> usize {
+        usize::try_from(cursor.position()).unwrap_or(usize::MAX)
+    }
+
+    impl<T: Len> Empty for Cursor<T> {
+        fn is_empty(&self) -> bool {
+            self.get_ref().is_empty()
+        }
+    }
+    impl<T: Len> Len for Cursor<T> {
+        fn len(&self) -> usize {
+            self.get_ref().len()
+        }
+    }
+    impl<T: Len> RemainingLen for Cursor<T> {
+        fn remaining_len(&self) -> usize {
+            self.get_ref().len().saturating_sub(position(self))
+        }
+    }
+    impl<T: Clear> Clear for Cursor<T> {
+        fn clear(&mut self) {
+            self.get_mut().clear();
+            self.set_position(0);
+        }
+    }
+    impl LenMut for Cursor<Vec<u8>> {
+        fn truncate(&mut self, len: usize) {
+            self.get_mut().truncate(len);
+            if position(self) > len {
+                self.set_position(len as u64);
+            }
+        }
+        fn split_off(&mut self, index: usize) -> Self {
+            let rest = Cursor::new(self.get_mut().split_off(index));
+            if position(self) > index {
+                self.set_position(index as u64);
+            }
+            rest
+        }
+    }
+
+    impl<R> Empty for BufReader<R> {
+        fn is_empty(&self) -> bool {
+            self.buffer().is_empty()
+        }
+    }
+    impl<R> Len for BufReader<R> {
+        fn len(&self) -> usize {
+            self.buffer().len()
+        }
+    }
+    impl<R> Capacity for BufReader<R> {
+        fn capacity(&self) -> usize {
+            BufReader::capacity(self)
+        }
+    }
+
+    impl<W: Write> Empty for BufWriter<W> {
+        fn is_empty(&self) -> bool {
+            self.buffer().is_empty()
+        }
+    }
+    impl<W: Write> Len for BufWriter<W> {
+        fn len(&self) -> usize {
+            self.buffer().len()
+        }
+    }
+    impl<W: Write> Capacity for BufWriter<W> {
+        fn capacity(&self) -> usize {
+            BufWriter::capacity(self)
+        }
+    }
+}
 // cfg_if::cfg_if! {
 //             if #[cfg(feature = indexmap)] {
 //             do_impl!(Len for indexmap::HashSet, indexmap::HashSet<T>; T: Eq + Hash);
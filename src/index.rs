@@ -3,30 +3,85 @@
  use std::ops;
 
 
-use self::ops::{Index, IndexMut, Range, RangeFrom, RangeFull, RangeTo};
+use self::ops::{Bound, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive,
+                RangeTo, RangeToInclusive};
 
+use super::error::{IndexError, NotCharBoundary, OutOfBounds};
+use super::len::Len;
 
-mod private {
-    pub trait Sealed {}
-    impl Sealed for str {}
-    impl<T> Sealed for [T] {}
-}
 
 
+/// A marker trait for collections which opt in to [`IndexRange`] and [`IndexRangeMut`].
+///
+/// Implementing the `Index` impls alone isn't enough to get the shorthand traits, so that a type
+/// only advertises consistent slicing when it deliberately does so. It's implemented for `[T]`,
+/// `[T; N]`, `Vec<T>`, `str` and `String`.
+///
+/// [`IndexRange`]: trait.IndexRange.html
+/// [`IndexRangeMut`]: trait.IndexRangeMut.html
+pub trait Slicable {}
+
 /// Shorthand trait for collections which offer consistent, immutable slicing.
 ///
-/// Here, "consistent" means that indexing is implemented for all of the `Range` types from the
-/// standard library, and the type returned by each of the slices is always the same.
+/// Here, "consistent" means that indexing is implemented for all of the half-open `Range` types
+/// from the standard library, and the type returned by each of the slices is always the same.
+/// Collections which can also be sliced with inclusive ranges implement
+/// [`IndexRangeInclusive`] too.
+///
+/// This trait is automatically implemented for every [`Slicable`] type which implements these
+/// `Index` impls, so third-party slice types opt in by providing them and implementing `Slicable`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::ops::{Index, Range, RangeFrom, RangeFull, RangeTo};
+/// use len_trait::{Slicable, SplitAt};
+///
+/// struct Buffer(Vec<u8>);
+///
+/// macro_rules! index_buffer {
+///     ($($range:ty),*) => {
+///         $(
+///             impl Index<$range> for Buffer {
+///                 type Output = [u8];
+///                 fn index(&self, range: $range) -> &[u8] {
+///                     &self.0[range]
+///                 }
+///             }
+///         )*
+///     };
+/// }
+/// index_buffer!(Range<usize>, RangeTo<usize>, RangeFrom<usize>, RangeFull);
 ///
-/// Currently, because inclusive ranges are unstable, and ranges starting with an exclusive bound
-/// don't exist, this trait can't be implemented for anything other than `str` and `[T]`. Hopefully,
-/// this will change in the future.
+/// impl Slicable for Buffer {}
+///
+/// impl SplitAt<usize> for Buffer {
+///     fn split_at(&self, index: usize) -> (&[u8], &[u8]) {
+///         self.0.split_at(index)
+///     }
+/// }
+///
+/// fn check_split<C>(collection: &C)
+/// where
+///     C: ?Sized + SplitAt<usize> + Index<RangeFull, Output = [u8]>,
+/// {
+///     let (left, right) = collection.split_at(1);
+///     assert_eq!(left, &collection[..1]);
+///     assert_eq!(right, &collection[1..]);
+/// }
+///
+/// check_split(&Buffer(vec![1, 2, 3, 4]));
+/// check_split(&[1, 2, 3, 4][..]);
+/// ```
+///
+/// [`IndexRangeInclusive`]: trait.IndexRangeInclusive.html
+/// [`Slicable`]: trait.Slicable.html
 pub trait IndexRange<Idx>
     : Index<Range<Idx>, Output = <Self as Index<RangeFull>>::Output>
     + Index<RangeTo<Idx>, Output = <Self as Index<RangeFull>>::Output>
     + Index<RangeFrom<Idx>, Output = <Self as Index<RangeFull>>::Output>
     + Index<RangeFull>
-    + private::Sealed {
+    + Slicable {
 }
 impl<T: ?Sized, Idx> IndexRange<Idx> for T
 where
@@ -43,18 +98,17 @@ where
         Output = <T as Index<RangeFull>>::Output,
     >,
     T: Index<RangeFull>,
-    T: private::Sealed,
+    T: Slicable,
 {
 }
 
 /// Shorthand trait for collections which offer consistent, mutable slicing.
 ///
-/// Here, "consistent" means that indexing is implemented for all of the `Range` types from the
-/// standard library, and the type returned by each of the slices is always the same.
+/// Here, "consistent" means that indexing is implemented for all of the half-open `Range` types
+/// from the standard library, and the type returned by each of the slices is always the same.
 ///
-/// Currently, because inclusive ranges are unstable, and ranges starting with an exclusive bound
-/// don't exist, this trait can't be implemented for anything other than `str` and `[T]`. Hopefully,
-/// this will change in the future.
+/// Like `IndexRange`, this trait is automatically implemented for every type which implements
+/// these `IndexMut` impls.
 pub trait IndexRangeMut<Idx>
     : IndexMut<Range<Idx>>
     + IndexMut<RangeTo<Idx>>
@@ -73,15 +127,302 @@ where
 {
 }
 
+/// Shorthand trait for collections which can also be sliced with inclusive ranges.
+///
+/// This extends [`IndexRange`] with `RangeInclusive`, `RangeToInclusive` and pairs of `Bound`s,
+/// which can express ranges that start with an excluded bound. Like `IndexRange`, it's
+/// automatically implemented for every type which implements these `Index` impls.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::ops::{Bound, Index, RangeFull};
+/// use len_trait::IndexRangeInclusive;
+///
+/// fn check_inclusive<C>(collection: &C)
+/// where
+///     C: ?Sized + IndexRangeInclusive<usize> + Index<RangeFull, Output = [u8]>,
+/// {
+///     assert_eq!(&collection[..=0], &collection[..1]);
+///     assert_eq!(&collection[1..=2], &collection[1..3]);
+///     assert_eq!(&collection[(Bound::Excluded(0), Bound::Unbounded)], &collection[1..]);
+/// }
+///
+/// check_inclusive(&[1, 2, 3, 4][..]);
+/// check_inclusive(&vec![1, 2, 3, 4]);
+/// ```
+///
+/// [`IndexRange`]: trait.IndexRange.html
+pub trait IndexRangeInclusive<Idx>
+    : Index<RangeInclusive<Idx>, Output = <Self as Index<RangeFull>>::Output>
+    + Index<RangeToInclusive<Idx>, Output = <Self as Index<RangeFull>>::Output>
+    + Index<(Bound<Idx>, Bound<Idx>), Output = <Self as Index<RangeFull>>::Output>
+    + IndexRange<Idx> {
+}
+impl<T: ?Sized, Idx> IndexRangeInclusive<Idx> for T
+where
+    T: Index<
+        RangeInclusive<Idx>,
+        Output = <T as Index<RangeFull>>::Output,
+    >,
+    T: Index<
+        RangeToInclusive<Idx>,
+        Output = <T as Index<RangeFull>>::Output,
+    >,
+    T: Index<
+        (Bound<Idx>, Bound<Idx>),
+        Output = <T as Index<RangeFull>>::Output,
+    >,
+    T: IndexRange<Idx>,
+{
+}
+
+/// Shorthand trait for collections which can also be mutably sliced with inclusive ranges.
+///
+/// Like `IndexRangeInclusive`, this trait is automatically implemented for every type which
+/// implements these `IndexMut` impls.
+pub trait IndexRangeInclusiveMut<Idx>
+    : IndexMut<RangeInclusive<Idx>>
+    + IndexMut<RangeToInclusive<Idx>>
+    + IndexMut<(Bound<Idx>, Bound<Idx>)>
+    + IndexRangeInclusive<Idx>
+    + IndexRangeMut<Idx> {
+}
+
+impl<T: ?Sized, Idx> IndexRangeInclusiveMut<Idx> for T
+where
+    T: IndexMut<RangeInclusive<Idx>>,
+    T: IndexMut<RangeToInclusive<Idx>>,
+    T: IndexMut<(Bound<Idx>, Bound<Idx>)>,
+    T: IndexRangeInclusive<Idx>,
+    T: IndexRangeMut<Idx>,
+{
+}
+
+/// A trait for slicing a collection with any range of indices.
+///
+/// While `IndexRange` requires choosing a concrete range type, the methods here accept anything
+/// implementing `RangeBounds<usize>`, including ranges which start with an excluded bound. They
+/// are available for every `IndexRange<usize>` implementor in the standard library: `[T]`,
+/// `[T; N]`, `Vec<T>`, `str` and `String`.
+///
+/// Other `IndexRange<usize>` implementors can opt in with an empty impl, which accepts every index
+/// up to the length. This isn't done automatically, because types which can only be sliced at some
+/// indices, like strings, must override [`is_slice_boundary`] so that the checked methods never
+/// panic.
+///
+/// Slicing a collection must take a constant amount of time and space.
+///
+/// # Examples
+///
+/// ```rust
+/// use len_trait::GetRange;
+///
+/// let vec = vec![1, 2, 3];
+/// assert_eq!(vec.get_range(1..), Some(&[2, 3][..]));
+/// assert_eq!([1, 2, 3].get_range(..4), None);
+///
+/// let string = "中文".to_string();
+/// assert_eq!(string.get_range(..3), Some("中"));
+/// assert_eq!(string.get_range(..1), None);
+/// ```
+///
+/// [`is_slice_boundary`]: #method.is_slice_boundary
+pub trait GetRange: IndexRange<usize> + Len {
+    /// Returns whether the given index can be used as the start or end of a slice.
+    ///
+    /// By default, this is every index up to and including the length of the collection. For
+    /// `str`, the index must also lie on a character boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::GetRange;
+    ///
+    /// assert!(GetRange::is_slice_boundary(&[1, 2, 3][..], 3));
+    /// assert!(!GetRange::is_slice_boundary(&[1, 2, 3][..], 4));
+    /// assert!(!GetRange::is_slice_boundary("中文", 1));
+    /// ```
+    fn is_slice_boundary(&self, index: usize) -> bool {
+        index <= self.len()
+    }
+
+    /// Slices the collection with the given range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is invalid according to the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::ops::Bound;
+    /// use len_trait::GetRange;
+    ///
+    /// let slice = &[1, 2, 3, 4][..];
+    /// assert_eq!(slice.slice(1..3), &[2, 3]);
+    /// assert_eq!(slice.slice(..=1), &[1, 2]);
+    /// assert_eq!(slice.slice((Bound::Excluded(1), Bound::Unbounded)), &[3, 4]);
+    /// assert_eq!(GetRange::slice("Hello", 1..), "ello");
+    /// ```
+    fn slice<R: RangeBounds<usize>>(&self, range: R) -> &<Self as Index<RangeFull>>::Output {
+        &self[to_range(self, &range)]
+    }
+
+    /// Slices the collection with the given range, returning `None` if the range is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::ops::Bound;
+    /// use len_trait::GetRange;
+    ///
+    /// let slice = &[1, 2, 3, 4][..];
+    /// assert_eq!(slice.get_range(1..3), Some(&[2, 3][..]));
+    /// assert_eq!(slice.get_range(3..2), None);
+    /// assert_eq!(slice.get_range(..=4), None);
+    /// assert_eq!(slice.get_range((Bound::Excluded(usize::MAX), Bound::Unbounded)), None);
+    /// assert_eq!(GetRange::get_range("中文", 3..), Some("文"));
+    /// assert_eq!(GetRange::get_range("中文", 1..), None);
+    /// ```
+    fn get_range<R: RangeBounds<usize>>(
+        &self,
+        range: R,
+    ) -> Option<&<Self as Index<RangeFull>>::Output> {
+        let range = checked_range(self, &range)?;
+        Some(&self[range])
+    }
+
+    /// Mutably slices the collection with the given range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is invalid according to the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::GetRange;
+    ///
+    /// let mut array = [1, 2, 3, 4];
+    /// array[..].slice_mut(2..).copy_from_slice(&[5, 6]);
+    /// assert_eq!(array, [1, 2, 5, 6]);
+    /// ```
+    fn slice_mut<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> &mut <Self as Index<RangeFull>>::Output
+    where
+        Self: IndexRangeMut<usize>,
+    {
+        let range = to_range(self, &range);
+        &mut self[range]
+    }
+
+    /// Mutably slices the collection with the given range, returning `None` if the range is
+    /// invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::GetRange;
+    ///
+    /// let mut array = [1, 2, 3, 4];
+    /// array[..].get_range_mut(..2).unwrap().copy_from_slice(&[5, 6]);
+    /// assert_eq!(array, [5, 6, 3, 4]);
+    /// assert!(array[..].get_range_mut(..5).is_none());
+    /// ```
+    fn get_range_mut<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> Option<&mut <Self as Index<RangeFull>>::Output>
+    where
+        Self: IndexRangeMut<usize>,
+    {
+        let range = checked_range(self, &range)?;
+        Some(&mut self[range])
+    }
+}
+
+/// Converts any range into a `Range`, panicking if one of its bounds overflows.
+///
+/// The range isn't checked against the collection; that is left to its `Index` impls.
+fn to_range<C, R>(collection: &C, range: &R) -> Range<usize>
+where
+    C: ?Sized + Len,
+    R: RangeBounds<usize>,
+{
+    bounds(collection, range).expect("range bound overflows usize")
+}
+
+/// Converts any range into a `Range`, if it is valid for the collection.
+fn checked_range<C, R>(collection: &C, range: &R) -> Option<Range<usize>>
+where
+    C: ?Sized + GetRange,
+    R: RangeBounds<usize>,
+{
+    let range = bounds(collection, range)?;
+    if range.start <= range.end &&
+        collection.is_slice_boundary(range.start) &&
+        collection.is_slice_boundary(range.end)
+    {
+        Some(range)
+    } else {
+        None
+    }
+}
+
+/// Converts any range into a `Range`, returning `None` if one of its bounds overflows.
+fn bounds<C, R>(collection: &C, range: &R) -> Option<Range<usize>>
+where
+    C: ?Sized + Len,
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => collection.len(),
+    };
+    Some(start..end)
+}
+
 /// A trait for splitting a collection into two pieces at a given index.
 ///
+/// This is implemented for collections which offer consistent slicing: `[T]`, `[T; N]`, `Vec<T>`,
+/// `str` and `String`. Containers which only dereference to a slice, like `Box<[T]>` or
+/// `Arc<str>`, can be split through [`SplitSlice`] instead.
+///
 /// Splitting a collection must take a constant amount of time and space.
+///
+/// [`SplitSlice`]: trait.SplitSlice.html
 pub trait SplitAt<Idx>: IndexRange<Idx> {
     /// Splits the collection into two pieces at the given index.
     ///
     /// # Panics
     ///
     /// Panics if `index` is invalid according to the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::ops::{Index, RangeFull};
+    /// use len_trait::SplitAt;
+    ///
+    /// fn check_split<C>(collection: &C)
+    /// where
+    ///     C: ?Sized + SplitAt<usize> + Index<RangeFull, Output = str>,
+    /// {
+    ///     assert_eq!(collection.split_at(5), ("Hello", ", world!"));
+    /// }
+    ///
+    /// check_split("Hello, world!");
+    /// check_split(&"Hello, world!".to_string());
+    /// ```
     fn split_at(
         &self,
         index: Idx,
@@ -97,8 +438,485 @@ pub trait SplitAtMut<Idx>: IndexRangeMut<Idx> + SplitAt<Idx> {
     /// # Panics
     ///
     /// Panics if `index` is invalid according to the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::ops::{Index, RangeFull};
+    /// use len_trait::SplitAtMut;
+    ///
+    /// fn swap_halves<C>(collection: &mut C)
+    /// where
+    ///     C: ?Sized + SplitAtMut<usize> + Index<RangeFull, Output = [u8]>,
+    /// {
+    ///     let (left, right) = collection.split_at_mut(2);
+    ///     left.swap_with_slice(right);
+    /// }
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// swap_halves(&mut vec);
+    /// assert_eq!(vec, [3, 4, 1, 2]);
+    /// ```
     fn split_at_mut(
         &mut self,
         index: Idx,
     ) -> (&mut <Self as Index<RangeFull>>::Output, &mut <Self as Index<RangeFull>>::Output);
-}
\ No newline at end of file
+}
+
+/// A trait for splitting a collection into two pieces of its slice type at a given index.
+///
+/// The pieces are borrowed as the slice type which the collection dereferences to, e.g. `[T]` for
+/// `Vec<T>` and `Box<[T]>`, or `str` for `String` and `Arc<str>`. This way, generic code can split
+/// owned and shared containers just like the slices they dereference to, even when they can't be
+/// indexed by ranges themselves.
+///
+/// The method is named `split_slice` rather than `split_at` so that calling it is never ambiguous
+/// with [`SplitAt`], which is implemented for some of the same types.
+///
+/// Splitting a collection must take a constant amount of time and space.
+///
+/// [`SplitAt`]: trait.SplitAt.html
+pub trait SplitSlice<Idx> {
+    /// The type of the two pieces.
+    type Slice: ?Sized;
+
+    /// Splits the collection into two pieces at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is invalid according to the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use len_trait::SplitSlice;
+    ///
+    /// fn check_split<C: ?Sized + SplitSlice<usize, Slice = str>>(collection: &C) {
+    ///     assert_eq!(collection.split_slice(5), ("Hello", ", world!"));
+    /// }
+    ///
+    /// check_split("Hello, world!");
+    /// check_split(&"Hello, world!".to_string());
+    /// check_split(&Arc::<str>::from("Hello, world!"));
+    /// ```
+    fn split_slice(&self, index: Idx) -> (&Self::Slice, &Self::Slice);
+}
+
+/// A trait for splitting a collection into two mutable pieces of its slice type at a given index.
+///
+/// Splitting a collection must take a constant amount of time and space.
+pub trait SplitSliceMut<Idx>: SplitSlice<Idx> {
+    /// Splits the collection into two mutable pieces at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is invalid according to the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::SplitSliceMut;
+    ///
+    /// fn swap_halves<C: ?Sized + SplitSliceMut<usize, Slice = [u8]>>(collection: &mut C) {
+    ///     let (left, right) = collection.split_slice_mut(2);
+    ///     left.swap_with_slice(right);
+    /// }
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// swap_halves(&mut vec);
+    /// assert_eq!(vec, [3, 4, 1, 2]);
+    ///
+    /// let mut boxed: Box<[u8]> = Box::new([1, 2, 3, 4]);
+    /// swap_halves(&mut boxed);
+    /// assert_eq!(&*boxed, [3, 4, 1, 2]);
+    /// ```
+    fn split_slice_mut(&mut self, index: Idx) -> (&mut Self::Slice, &mut Self::Slice);
+}
+
+/// A trait for splitting a collection in two, returning an error if the index is invalid.
+///
+/// This is automatically implemented for every collection which can be split at a `usize` into
+/// slices implementing [`GetRange`].
+///
+/// Splitting a collection must take a constant amount of time and space.
+///
+/// [`GetRange`]: trait.GetRange.html
+pub trait TrySplitAt: SplitSlice<usize> {
+    /// Splits the collection into two pieces at the given index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `index > len`, or if `index` doesn't lie on a character boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::TrySplitAt;
+    /// use len_trait::error::{IndexError, NotCharBoundary, OutOfBounds};
+    ///
+    /// let string = "中文".to_string();
+    /// assert_eq!(string.try_split_at(3), Ok(("中", "文")));
+    /// assert_eq!(
+    ///     string.try_split_at(1),
+    ///     Err(IndexError::NotCharBoundary(NotCharBoundary { index: 1 })),
+    /// );
+    /// assert_eq!(
+    ///     vec![1, 2].try_split_at(3),
+    ///     Err(IndexError::OutOfBounds(OutOfBounds { index: 3, len: 2 })),
+    /// );
+    /// ```
+    fn try_split_at(&self, index: usize) -> Result<(&Self::Slice, &Self::Slice), IndexError>;
+
+    /// Splits the collection into two mutable pieces at the given index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `index > len`, or if `index` doesn't lie on a character boundary.
+    fn try_split_at_mut(
+        &mut self,
+        index: usize,
+    ) -> Result<(&mut Self::Slice, &mut Self::Slice), IndexError>
+    where
+        Self: SplitSliceMut<usize>;
+}
+
+impl<C: ?Sized + SplitSlice<usize>> TrySplitAt for C
+where
+    C::Slice: GetRange,
+{
+    fn try_split_at(&self, index: usize) -> Result<(&C::Slice, &C::Slice), IndexError> {
+        check_boundary(whole(self), index)?;
+        Ok(self.split_slice(index))
+    }
+
+    fn try_split_at_mut(
+        &mut self,
+        index: usize,
+    ) -> Result<(&mut C::Slice, &mut C::Slice), IndexError>
+    where
+        Self: SplitSliceMut<usize>,
+    {
+        check_boundary(whole(self), index)?;
+        Ok(self.split_slice_mut(index))
+    }
+}
+
+/// Checks that a slice can be split at the given index.
+fn check_boundary<S: ?Sized + GetRange>(slice: &S, index: usize) -> Result<(), IndexError> {
+    let len = slice.len();
+    if index > len {
+        Err(OutOfBounds { index, len }.into())
+    } else if !slice.is_slice_boundary(index) {
+        Err(NotCharBoundary { index }.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// A trait for slices which can be stepped through one element at a time.
+///
+/// For `[T]`, an element is a single item. For `str`, an element is a `char`, so that every index
+/// produced by stepping lies on a character boundary.
+///
+/// Stepping over `n` elements must take at most a linear amount of time with respect to `n`.
+pub trait SplitStep: Len + SplitSlice<usize, Slice = Self> {
+    /// Returns the index `n` elements after `index`, or `None` if there aren't `n` elements left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is invalid according to the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::SplitStep;
+    ///
+    /// assert_eq!(SplitStep::step_forward(&[1, 2, 3][..], 1, 2), Some(3));
+    /// assert_eq!(SplitStep::step_forward(&[1, 2, 3][..], 1, 3), None);
+    /// assert_eq!(SplitStep::step_forward("中文", 0, 1), Some(3));
+    /// ```
+    fn step_forward(&self, index: usize, n: usize) -> Option<usize>;
+
+    /// Returns the index `n` elements before `index`, or `None` if there aren't `n` elements
+    /// before it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is invalid according to the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::SplitStep;
+    ///
+    /// assert_eq!(SplitStep::step_backward(&[1, 2, 3][..], 3, 2), Some(1));
+    /// assert_eq!(SplitStep::step_backward(&[1, 2, 3][..], 1, 2), None);
+    /// assert_eq!(SplitStep::step_backward("中文", 6, 1), Some(3));
+    /// ```
+    fn step_backward(&self, index: usize, n: usize) -> Option<usize>;
+}
+
+/// A trait for splitting a collection into many pieces.
+///
+/// This is automatically implemented for every `SplitSlice<usize>` implementor whose slices
+/// implement `SplitStep`, including owned containers like `Vec<T>` and `String`. The pieces are
+/// always measured in elements, so strings are split into whole `char`s.
+///
+/// These methods are prefixed with `split_`, because the inherent slice methods `split_first`,
+/// `split_last`, `chunks`, `chunks_exact` and `windows` would take priority over methods of the
+/// same name on types like `Vec<T>`, and return elements instead of pieces of the collection.
+pub trait Split: SplitSlice<usize> {
+    /// Splits off the first element, returning it and the rest of the collection.
+    ///
+    /// Returns `None` if the collection is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::Split;
+    ///
+    /// assert_eq!(vec![1, 2, 3].split_first_elem(), Some((&[1][..], &[2, 3][..])));
+    /// assert_eq!("中文".split_first_elem(), Some(("中", "文")));
+    /// assert_eq!("".split_first_elem(), None);
+    /// ```
+    fn split_first_elem(&self) -> Option<(&Self::Slice, &Self::Slice)>;
+
+    /// Splits off the last element, returning it and the rest of the collection.
+    ///
+    /// Returns `None` if the collection is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::Split;
+    ///
+    /// assert_eq!(vec![1, 2, 3].split_last_elem(), Some((&[3][..], &[1, 2][..])));
+    /// assert_eq!("中文".split_last_elem(), Some(("文", "中")));
+    /// assert_eq!("".split_last_elem(), None);
+    /// ```
+    fn split_last_elem(&self) -> Option<(&Self::Slice, &Self::Slice)>;
+
+    /// Splits the collection at each of the given indices, in one pass.
+    ///
+    /// This returns one more piece than the number of indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices aren't in ascending order, or if any of them is invalid according to
+    /// the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::Split;
+    ///
+    /// let packet = b"\x01\x00\x05hello".to_vec();
+    /// let pieces = packet.split_at_many(&[1, 3]);
+    /// assert_eq!(pieces, [&b"\x01"[..], &b"\x00\x05"[..], &b"hello"[..]]);
+    ///
+    /// assert_eq!("key=value".split_at_many(&[3, 4]), ["key", "=", "value"]);
+    /// ```
+    fn split_at_many(&self, indices: &[usize]) -> Vec<&Self::Slice>;
+
+    /// Returns an iterator over pieces of `size` elements, the last of which may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::Split;
+    ///
+    /// let vec = vec![1, 2, 3, 4, 5];
+    /// let chunks: Vec<_> = vec.split_chunks(2).collect();
+    /// assert_eq!(chunks, [&[1, 2][..], &[3, 4][..], &[5][..]]);
+    ///
+    /// let chunks: Vec<_> = "Grüße".split_chunks(2).collect();
+    /// assert_eq!(chunks, ["Gr", "üß", "e"]);
+    /// ```
+    fn split_chunks(&self, size: usize) -> SplitChunks<'_, Self::Slice>;
+
+    /// Returns an iterator over pieces of exactly `size` elements.
+    ///
+    /// Any leftover elements are available from `SplitChunksExact::remainder`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::Split;
+    ///
+    /// let mut chunks = "Grüße".split_chunks_exact(2);
+    /// assert_eq!(chunks.remainder(), "e");
+    /// assert_eq!(chunks.next(), Some("Gr"));
+    /// assert_eq!(chunks.next(), Some("üß"));
+    /// assert_eq!(chunks.next(), None);
+    /// assert_eq!(chunks.remainder(), "e");
+    ///
+    /// let vec = vec![1, 2, 3, 4, 5, 6, 7];
+    /// let chunks = vec.split_chunks_exact(3);
+    /// assert_eq!(chunks.remainder(), [7]);
+    /// assert_eq!(chunks.collect::<Vec<_>>(), [&[1, 2, 3][..], &[4, 5, 6][..]]);
+    /// ```
+    fn split_chunks_exact(&self, size: usize) -> SplitChunksExact<'_, Self::Slice>;
+
+    /// Returns an iterator over all overlapping pieces of `size` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::Split;
+    ///
+    /// let vec = vec![1, 2, 3];
+    /// let windows: Vec<_> = vec.split_windows(2).collect();
+    /// assert_eq!(windows, [&[1, 2][..], &[2, 3][..]]);
+    ///
+    /// let windows: Vec<_> = "中文字".split_windows(2).collect();
+    /// assert_eq!(windows, ["中文", "文字"]);
+    /// ```
+    fn split_windows(&self, size: usize) -> SplitWindows<'_, Self::Slice>;
+}
+
+impl<C: ?Sized + SplitSlice<usize>> Split for C
+where
+    C::Slice: SplitStep,
+{
+    fn split_first_elem(&self) -> Option<(&C::Slice, &C::Slice)> {
+        let slice = whole(self);
+        let mid = slice.step_forward(0, 1)?;
+        Some(slice.split_slice(mid))
+    }
+
+    fn split_last_elem(&self) -> Option<(&C::Slice, &C::Slice)> {
+        let slice = whole(self);
+        let mid = slice.step_backward(slice.len(), 1)?;
+        let (rest, last) = slice.split_slice(mid);
+        Some((last, rest))
+    }
+
+    fn split_at_many(&self, indices: &[usize]) -> Vec<&C::Slice> {
+        let mut pieces = Vec::with_capacity(indices.len() + 1);
+        let mut rest = whole(self);
+        let mut offset = 0;
+        for &index in indices {
+            assert!(index >= offset, "split indices must be in ascending order");
+            let (piece, next) = rest.split_slice(index - offset);
+            pieces.push(piece);
+            rest = next;
+            offset = index;
+        }
+        pieces.push(rest);
+        pieces
+    }
+
+    fn split_chunks(&self, size: usize) -> SplitChunks<'_, C::Slice> {
+        assert!(size != 0, "chunk size must be non-zero");
+        SplitChunks { rest: whole(self), size }
+    }
+
+    fn split_chunks_exact(&self, size: usize) -> SplitChunksExact<'_, C::Slice> {
+        assert!(size != 0, "chunk size must be non-zero");
+        let slice = whole(self);
+        let mut end = 0;
+        while let Some(next) = slice.step_forward(end, size) {
+            end = next;
+        }
+        let (rest, remainder) = slice.split_slice(end);
+        SplitChunksExact { rest, remainder, size }
+    }
+
+    fn split_windows(&self, size: usize) -> SplitWindows<'_, C::Slice> {
+        assert!(size != 0, "window size must be non-zero");
+        SplitWindows { rest: whole(self), size }
+    }
+}
+
+/// Borrows the entire collection as its slice type.
+fn whole<C: ?Sized + SplitSlice<usize>>(collection: &C) -> &C::Slice {
+    // Splitting at zero is always valid, and leaves everything in the second piece.
+    collection.split_slice(0).1
+}
+
+/// An iterator over pieces of a collection, created by [`Split::split_chunks`].
+///
+/// [`Split::split_chunks`]: trait.Split.html#tymethod.split_chunks
+#[derive(Clone, Debug)]
+pub struct SplitChunks<'a, S: ?Sized> {
+    rest: &'a S,
+    size: usize,
+}
+
+impl<'a, S: ?Sized + SplitStep> Iterator for SplitChunks<'a, S> {
+    type Item = &'a S;
+
+    fn next(&mut self) -> Option<&'a S> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let mid = self.rest.step_forward(0, self.size).unwrap_or_else(|| self.rest.len());
+        let (chunk, rest) = self.rest.split_slice(mid);
+        self.rest = rest;
+        Some(chunk)
+    }
+}
+
+/// An iterator over pieces of a collection, created by [`Split::split_chunks_exact`].
+///
+/// [`Split::split_chunks_exact`]: trait.Split.html#tymethod.split_chunks_exact
+#[derive(Clone, Debug)]
+pub struct SplitChunksExact<'a, S: ?Sized> {
+    rest: &'a S,
+    remainder: &'a S,
+    size: usize,
+}
+
+impl<'a, S: ?Sized> SplitChunksExact<'a, S> {
+    /// Returns the elements after the last whole chunk, which the iterator never yields.
+    ///
+    /// This is the same whether or not the iterator has been used.
+    pub fn remainder(&self) -> &'a S {
+        self.remainder
+    }
+}
+
+impl<'a, S: ?Sized + SplitStep> Iterator for SplitChunksExact<'a, S> {
+    type Item = &'a S;
+
+    fn next(&mut self) -> Option<&'a S> {
+        let mid = self.rest.step_forward(0, self.size)?;
+        let (chunk, rest) = self.rest.split_slice(mid);
+        self.rest = rest;
+        Some(chunk)
+    }
+}
+
+/// An iterator over overlapping pieces of a collection, created by [`Split::split_windows`].
+///
+/// [`Split::split_windows`]: trait.Split.html#tymethod.split_windows
+#[derive(Clone, Debug)]
+pub struct SplitWindows<'a, S: ?Sized> {
+    rest: &'a S,
+    size: usize,
+}
+
+impl<'a, S: ?Sized + SplitStep> Iterator for SplitWindows<'a, S> {
+    type Item = &'a S;
+
+    fn next(&mut self) -> Option<&'a S> {
+        let end = self.rest.step_forward(0, self.size)?;
+        let window = self.rest.split_slice(end).0;
+        let next = self.rest.step_forward(0, 1)?;
+        self.rest = self.rest.split_slice(next).1;
+        Some(window)
+    }
+}
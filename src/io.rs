@@ -0,0 +1,103 @@
+//! Traits involving the buffers used for I/O.
+//!
+//! Along with the traits here, this crate implements:
+//!
+//! * `Len` and `Clear` for `Cursor<T>`, measuring the whole of the underlying buffer
+//! * `LenMut` for `Cursor<Vec<u8>>`, which moves the position back if it's past the end
+//! * `Len` and `Capacity` for `BufReader<R>` and `BufWriter<W>`, measuring the bytes in their
+//!   internal buffers
+//!
+//! `LineWriter` doesn't expose its internal buffer, so it can't implement these traits.
+//!
+//! # Examples
+//!
+//! Clearing a cursor also moves it back to the start:
+//!
+//! ```rust
+//! use std::io::{Cursor, Write};
+//! use len_trait::{Clear, Len, RemainingLen};
+//!
+//! let mut cursor = Cursor::new(Vec::new());
+//! cursor.write_all(b"hello").unwrap();
+//! assert_eq!(Len::len(&cursor), 5);
+//! assert_eq!(cursor.position(), 5);
+//!
+//! Clear::clear(&mut cursor);
+//! assert!(cursor.get_ref().is_empty());
+//! assert_eq!(cursor.position(), 0);
+//! assert_eq!(cursor.remaining_len(), 0);
+//! ```
+//!
+//! Truncating or splitting a cursor moves its position back if it's past the new end:
+//!
+//! ```rust
+//! use std::io::Cursor;
+//! use len_trait::{Len, LenMut};
+//!
+//! let mut cursor = Cursor::new(b"hello world".to_vec());
+//! cursor.set_position(8);
+//! LenMut::truncate(&mut cursor, 5);
+//! assert_eq!(cursor.get_ref(), b"hello");
+//! assert_eq!(cursor.position(), 5);
+//!
+//! cursor.set_position(4);
+//! let rest = LenMut::split_off(&mut cursor, 2);
+//! assert_eq!(cursor.get_ref(), b"he");
+//! assert_eq!(cursor.position(), 2);
+//! assert_eq!(rest.get_ref(), b"llo");
+//! assert_eq!(rest.position(), 0);
+//!
+//! cursor.set_position(1);
+//! LenMut::truncate(&mut cursor, 2);
+//! assert_eq!(cursor.position(), 1);
+//! ```
+//!
+//! Buffered readers and writers measure the bytes in their buffers:
+//!
+//! ```rust
+//! use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+//! use len_trait::{Capacity, Len};
+//!
+//! let mut reader = BufReader::with_capacity(8, &b"hello world"[..]);
+//! assert_eq!(Len::len(&reader), 0);
+//! assert_eq!(Capacity::capacity(&reader), 8);
+//!
+//! let mut word = [0; 3];
+//! reader.read_exact(&mut word).unwrap();
+//! assert_eq!(Len::len(&reader), 5);
+//! reader.consume(5);
+//! assert_eq!(Len::len(&reader), 0);
+//!
+//! let mut writer = BufWriter::with_capacity(16, Vec::new());
+//! writer.write_all(b"hello").unwrap();
+//! assert_eq!(Len::len(&writer), 5);
+//! assert_eq!(Capacity::capacity(&writer), 16);
+//! assert!(writer.get_ref().is_empty());
+//!
+//! writer.flush().unwrap();
+//! assert_eq!(Len::len(&writer), 0);
+//! assert_eq!(writer.get_ref(), b"hello");
+//! ```
+
+/// A trait for describing how much of a collection is left to be read.
+///
+/// Obtaining the remaining length must take a constant amount of time and space.
+pub trait RemainingLen {
+    /// Returns the number of elements which haven't been read yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::{Cursor, Read};
+    /// use len_trait::{Len, RemainingLen};
+    ///
+    /// let mut cursor = Cursor::new(vec![1, 2, 3, 4]);
+    /// cursor.read_exact(&mut [0; 3]).unwrap();
+    /// assert_eq!(Len::len(&cursor), 4);
+    /// assert_eq!(cursor.remaining_len(), 1);
+    ///
+    /// cursor.set_position(10);
+    /// assert_eq!(cursor.remaining_len(), 0);
+    /// ```
+    fn remaining_len(&self) -> usize;
+}
@@ -0,0 +1,286 @@
+//! Adapters between collections and iterators which know their exact length.
+use super::len::{Empty, Len};
+
+/// A wrapper which describes the length of an `ExactSizeIterator` without consuming it.
+///
+/// This lets an iterator like `vec.iter().map(..)` be passed wherever a `Len` is expected. The
+/// wrapper is also an iterator itself, yielding the same items as the one it wraps.
+///
+/// # Examples
+///
+/// ```rust
+/// use len_trait::{ExactLen, Len};
+///
+/// fn count<C: ?Sized + Len>(collection: &C) -> usize {
+///     collection.len()
+/// }
+///
+/// let vec = vec![1, 2, 3];
+/// let mut doubled = ExactLen::new(vec.iter().map(|x| x * 2));
+/// assert_eq!(count(&doubled), 3);
+/// assert_eq!(doubled.next(), Some(2));
+/// assert_eq!(count(&doubled), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ExactLen<I> {
+    iter: I,
+}
+
+impl<I: ExactSizeIterator> ExactLen<I> {
+    /// Wraps the given iterator.
+    pub fn new(iter: I) -> Self {
+        ExactLen { iter }
+    }
+
+    /// Returns the number of items left in the iterator.
+    ///
+    /// This is the same as both `Len::len` and `ExactSizeIterator::len`. These inherent methods
+    /// exist so that calling them is never ambiguous.
+    pub fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    /// Returns whether the iterator has no items left.
+    pub fn is_empty(&self) -> bool {
+        self.iter.len() == 0
+    }
+
+    /// Unwraps the iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: ExactSizeIterator> Empty for ExactLen<I> {
+    fn is_empty(&self) -> bool {
+        ExactLen::is_empty(self)
+    }
+}
+impl<I: ExactSizeIterator> Len for ExactLen<I> {
+    fn len(&self) -> usize {
+        ExactLen::len(self)
+    }
+}
+
+impl<I: Iterator> Iterator for ExactLen<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<I: DoubleEndedIterator> DoubleEndedIterator for ExactLen<I> {
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.iter.next_back()
+    }
+}
+impl<I: ExactSizeIterator> ExactSizeIterator for ExactLen<I> {}
+
+/// An iterator whose `size_hint` is taken from the length of the collection it came from.
+///
+/// This is created by the methods of [`LenIter`]. It implements `ExactSizeIterator` even when the
+/// underlying iterator doesn't, like the iterators of `HashMap` or `BTreeSet` chained through
+/// adapters which lose their length.
+///
+/// [`LenIter`]: trait.LenIter.html
+#[derive(Clone, Debug)]
+pub struct ExactIter<I> {
+    iter: I,
+    remaining: usize,
+}
+
+impl<I: Iterator> ExactIter<I> {
+    /// Wraps an iterator which will yield exactly `len` items.
+    ///
+    /// If the iterator yields a different number of items, its `size_hint` will be wrong. This
+    /// can't cause undefined behaviour, but may cause other code to behave incorrectly.
+    pub fn new(iter: I, len: usize) -> Self {
+        ExactIter { iter, remaining: len }
+    }
+
+    /// Unwraps the iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Iterator for ExactIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        match self.iter.next() {
+            Some(item) => {
+                self.remaining = self.remaining.saturating_sub(1);
+                Some(item)
+            }
+            None => {
+                self.remaining = 0;
+                None
+            }
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<I: DoubleEndedIterator> DoubleEndedIterator for ExactIter<I> {
+    fn next_back(&mut self) -> Option<I::Item> {
+        match self.iter.next_back() {
+            Some(item) => {
+                self.remaining = self.remaining.saturating_sub(1);
+                Some(item)
+            }
+            None => {
+                self.remaining = 0;
+                None
+            }
+        }
+    }
+}
+impl<I: Iterator> ExactSizeIterator for ExactIter<I> {}
+
+/// A trait for iterating over a collection with an exact `size_hint`, taken from its length.
+///
+/// This is automatically implemented for every `Len` implementor.
+pub trait LenIter: Len {
+    /// Iterates over references to the items of the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeSet;
+    /// use len_trait::LenIter;
+    ///
+    /// let set: BTreeSet<_> = (1..=3).collect();
+    /// let iter = set.len_iter();
+    /// assert_eq!(iter.size_hint(), (3, Some(3)));
+    /// assert_eq!(iter.len(), 3);
+    /// ```
+    fn len_iter<'a>(&'a self) -> ExactIter<<&'a Self as IntoIterator>::IntoIter>
+    where
+        &'a Self: IntoIterator,
+    {
+        ExactIter::new(self.into_iter(), self.len())
+    }
+
+    /// Consumes the collection, iterating over its items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::LinkedList;
+    /// use len_trait::LenIter;
+    ///
+    /// let list: LinkedList<_> = (1..=3).collect();
+    /// let iter = list.into_len_iter();
+    /// assert_eq!(iter.len(), 3);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    fn into_len_iter(self) -> ExactIter<<Self as IntoIterator>::IntoIter>
+    where
+        Self: IntoIterator + Sized,
+    {
+        let len = self.len();
+        ExactIter::new(self.into_iter(), len)
+    }
+}
+
+impl<C: ?Sized + Len> LenIter for C {}
+
+/// A trait for ranges of integers, which can count how many integers they contain.
+///
+/// Ranges are also iterators, so they don't implement [`Len`] directly: calling `len` on them
+/// would be ambiguous with `ExactSizeIterator::len`. This method has its own name instead, and
+/// returns `None` if the count doesn't fit in a `usize`, e.g. for `0..=usize::MAX`, or for
+/// `0..u64::MAX` on 32-bit targets.
+///
+/// Counting the integers must take a constant amount of time and space.
+///
+/// [`Len`]: trait.Len.html
+pub trait RangeLen {
+    /// Returns the number of integers in the range, or `None` if it overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::RangeLen;
+    ///
+    /// assert_eq!((10u8..20).range_len(), Some(10));
+    /// assert_eq!((-5i64..=5).range_len(), Some(11));
+    /// assert_eq!((5u32..5).range_len(), Some(0));
+    /// assert_eq!((0..=usize::MAX).range_len(), None);
+    /// assert_eq!((0..=u128::MAX).range_len(), None);
+    /// ```
+    fn range_len(&self) -> Option<usize>;
+}
+
+/// A wrapper which lets a range of integers be used wherever a `Len` is expected.
+///
+/// The length is counted once, when the wrapper is created, which fails if it would overflow
+/// `usize`. The wrapper only gives shared access to the range, so the length can't go stale.
+///
+/// # Examples
+///
+/// ```rust
+/// use len_trait::{ExactRange, Len};
+///
+/// fn count<C: ?Sized + Len>(collection: &C) -> usize {
+///     collection.len()
+/// }
+///
+/// let range = ExactRange::new(0..u64::from(u32::MAX)).unwrap();
+/// assert_eq!(count(&range), u32::MAX as usize);
+/// assert_eq!(range.len(), u32::MAX as usize);
+/// assert_eq!(range.into_inner(), 0..u64::from(u32::MAX));
+///
+/// assert!(ExactRange::new(0..=usize::MAX).is_none());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ExactRange<R> {
+    range: R,
+    len: usize,
+}
+
+impl<R: RangeLen> ExactRange<R> {
+    /// Wraps the given range, or returns `None` if its length overflows `usize`.
+    pub fn new(range: R) -> Option<Self> {
+        let len = range.range_len()?;
+        Some(ExactRange { range, len })
+    }
+}
+
+impl<R> ExactRange<R> {
+    /// Returns the number of integers in the range.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the range contains no integers.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the range.
+    pub fn get_ref(&self) -> &R {
+        &self.range
+    }
+
+    /// Unwraps the range.
+    pub fn into_inner(self) -> R {
+        self.range
+    }
+}
+
+impl<R> Empty for ExactRange<R> {
+    fn is_empty(&self) -> bool {
+        ExactRange::is_empty(self)
+    }
+}
+impl<R> Len for ExactRange<R> {
+    fn len(&self) -> usize {
+        ExactRange::len(self)
+    }
+}
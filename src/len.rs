@@ -1,5 +1,6 @@
 
 //! Traits involving the length of a collection.
+use super::error::IndexError;
 
 /// A trait for describing whether a collection is empty, i.e., its length is zero.
 ///
@@ -30,8 +31,12 @@ pub trait Empty {
 /// directly proportional to its length. For this reason, `str` and other types measure their
 /// lengths in code values (e.g. `u8`), not code points (e.g. `char`).
 ///
+/// Ranges of integers don't implement this trait, because they are also iterators and calling
+/// `len` on them would be ambiguous. Wrap them in an [`ExactRange`] instead.
+///
 /// Obtaining the length of the collection must take a constant amount of time and space.
-
+///
+/// [`ExactRange`]: struct.ExactRange.html
 // TODO: https://github.com/Manishearth/rust-clippy/issues/1740
 pub trait Len: Empty {
     /// Returns the length of the collection.
@@ -51,6 +56,122 @@ pub trait Len: Empty {
     fn len(&self) -> usize;
 }
 
+/// A trait for describing bounds on the length of a collection.
+///
+/// Unlike [`Len`], the length here doesn't have to be exact, which lets concurrent collections,
+/// lazy sequences and streaming buffers describe their length as well as they can. The bounds
+/// follow the same rules as `Iterator::size_hint`: the lower bound must be no more than the actual
+/// length, and the upper bound, if present, must be no less than it.
+///
+/// This is automatically implemented for every `Len` implementor, with both bounds equal to its
+/// length. The method is named `len_hint` rather than `size_hint` so that calling it is never
+/// ambiguous with `Iterator::size_hint` on collections which are also iterators, like
+/// [`ExactLen`].
+///
+/// Obtaining the bounds must take a constant amount of time and space.
+///
+/// [`Len`]: trait.Len.html
+/// [`ExactLen`]: struct.ExactLen.html
+pub trait SizeHint {
+    /// Returns the lower and optional upper bound on the length of the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::{SizeHint, WithCapacity};
+    ///
+    /// struct Stream {
+    ///     buffered: Vec<u8>,
+    ///     finished: bool,
+    /// }
+    ///
+    /// impl SizeHint for Stream {
+    ///     fn len_hint(&self) -> (usize, Option<usize>) {
+    ///         let buffered = self.buffered.len();
+    ///         (buffered, if self.finished { Some(buffered) } else { None })
+    ///     }
+    /// }
+    ///
+    /// fn preallocate<S: ?Sized + SizeHint, C: WithCapacity>(source: &S) -> C {
+    ///     C::with_capacity(source.len_hint().0)
+    /// }
+    ///
+    /// let exact: Vec<u32> = preallocate(&[1, 2, 3][..]);
+    /// assert!(exact.capacity() >= 3);
+    ///
+    /// let stream = Stream { buffered: vec![1, 2], finished: false };
+    /// let approximate: String = preallocate(&stream);
+    /// assert!(approximate.capacity() >= 2);
+    ///
+    /// // `ExactLen` is an iterator too, but the methods don't clash.
+    /// use len_trait::*;
+    /// let iter = ExactLen::new(0..10usize);
+    /// assert_eq!(iter.len_hint(), (10, Some(10)));
+    /// assert_eq!(iter.size_hint(), (10, Some(10)));
+    /// ```
+    fn len_hint(&self) -> (usize, Option<usize>);
+}
+
+impl<T: ?Sized + Len> SizeHint for T {
+    fn len_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+/// A trait for describing the length of a string in a particular unit.
+///
+/// While [`Len`] always measures strings in code values, text layout and interoperability with
+/// other languages often need a different unit. The unit is chosen with one of the marker types
+/// from the [`unit`] module.
+///
+/// Unlike `Len`, measuring a length here may take a linear amount of time with respect to the
+/// length of the string:
+///
+/// | Unit                    | `str`, `String` | `OsStr`, `OsString` | `CStr`, `CString` |
+/// |-------------------------|-----------------|---------------------|-------------------|
+/// | [`Bytes`]               | O(1)            | O(1)                | O(n)              |
+/// | [`Chars`]               | O(n)            | O(n)                | O(n)              |
+/// | [`Utf16`]               | O(n)            | O(n)                | O(n)              |
+/// | [`Graphemes`]           | O(n)            | O(n)                | O(n)              |
+///
+/// `CStr` is O(n) even for bytes because it has to search for its nul terminator.
+///
+/// Strings which are not guaranteed to be valid UTF-8, like `OsStr` and `CStr`, are measured as
+/// if they were converted with `to_string_lossy`, i.e. invalid sequences count as one replacement
+/// character each. Measuring these strings in a unit other than bytes will allocate if they aren't
+/// valid Unicode.
+///
+/// [`Len`]: trait.Len.html
+/// [`unit`]: ../unit/index.html
+/// [`Bytes`]: ../unit/enum.Bytes.html
+/// [`Chars`]: ../unit/enum.Chars.html
+/// [`Utf16`]: ../unit/enum.Utf16.html
+/// [`Graphemes`]: ../unit/enum.Graphemes.html
+pub trait LenIn<U> {
+    /// Returns the length of the string in the unit `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::LenIn;
+    /// use len_trait::unit::{Bytes, Chars, Utf16};
+    ///
+    /// fn widths<S: ?Sized + LenIn<Bytes> + LenIn<Chars> + LenIn<Utf16>>(s: &S) -> [usize; 3] {
+    ///     [
+    ///         LenIn::<Bytes>::len_in(s),
+    ///         LenIn::<Chars>::len_in(s),
+    ///         LenIn::<Utf16>::len_in(s),
+    ///     ]
+    /// }
+    ///
+    /// assert_eq!(widths("中文"), [6, 2, 2]);
+    /// assert_eq!(widths("🦀"), [4, 1, 2]);
+    /// assert_eq!(widths(&"abc".to_string()), [3, 3, 3]);
+    /// ```
+    fn len_in(&self) -> usize;
+}
+
 /// A trait for clearing collections.
 ///
 /// A collection is cleared by dropping all of its data. After `clear` is called, the collection is
@@ -139,4 +260,117 @@ pub trait LenMut: Default + Clear {
     /// check_split_off(vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
     /// ```
     fn split_off(&mut self, index: usize) -> Self;
-}
\ No newline at end of file
+}
+
+/// A trait for modifying the length of a collection, returning an error if an index is invalid.
+///
+/// Implementations only have to describe which indices are valid; the checked methods are provided
+/// on top of `LenMut`.
+///
+/// Checking an index must take a constant amount of time and space.
+pub trait TryLenMut: LenMut {
+    /// Checks whether the collection can be truncated or split at the given index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `index > len`, or if `index` isn't valid according to the collection.
+    /// For example, the implementation for `String` returns an error if `index` does not lie on a
+    /// character boundary.
+    fn check_boundary(&self, index: usize) -> Result<(), IndexError>;
+
+    /// Truncates the collection to be no greater than `len` long, dropping elements as needed.
+    ///
+    /// If the collection is less than `len` long, do nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error instead of panicking if `len` is not valid according to the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::TryLenMut;
+    /// use len_trait::error::{IndexError, NotCharBoundary};
+    ///
+    /// let mut string = "中文".to_string();
+    /// assert_eq!(
+    ///     string.try_truncate(4),
+    ///     Err(IndexError::NotCharBoundary(NotCharBoundary { index: 4 })),
+    /// );
+    /// assert_eq!(string.try_truncate(10), Ok(()));
+    /// assert_eq!(string.try_truncate(3), Ok(()));
+    /// assert_eq!(string, "中");
+    /// ```
+    fn try_truncate(&mut self, len: usize) -> Result<(), IndexError> {
+        if len < self.len() {
+            self.check_boundary(len)?;
+            self.truncate(len);
+        }
+        Ok(())
+    }
+
+    /// Splits off the collection at the given index, returning the data past the index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error instead of panicking if `index > len`, or if it is not valid according to
+    /// the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::TryLenMut;
+    /// use len_trait::error::{IndexError, OutOfBounds};
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// assert_eq!(
+    ///     vec.try_split_off(4),
+    ///     Err(IndexError::OutOfBounds(OutOfBounds { index: 4, len: 3 })),
+    /// );
+    /// assert_eq!(vec.try_split_off(1), Ok(vec![2, 3]));
+    /// ```
+    fn try_split_off(&mut self, index: usize) -> Result<Self, IndexError> {
+        self.check_boundary(index)?;
+        Ok(self.split_off(index))
+    }
+}
+
+/// A dyn-compatible counterpart to [`LenMut`].
+///
+/// `LenMut` requires `Default` and returns `Self` from `split_off`, so it can't be used as a trait
+/// object. This trait offers the methods which don't need `Self` by value, so that different kinds
+/// of collections can be stored together as `Box<dyn DynLenMut>`. It is automatically implemented
+/// for every `LenMut` implementor.
+///
+/// The method is prefixed with `dyn_` so that calling it is never ambiguous with `LenMut`.
+///
+/// # Examples
+///
+/// ```rust
+/// use len_trait::DynLenMut;
+///
+/// let mut collections: Vec<Box<dyn DynLenMut>> = vec![
+///     Box::new(vec![1, 2, 3]),
+///     Box::new("Hello, world!".to_string()),
+/// ];
+/// for collection in &mut collections {
+///     collection.dyn_truncate(2);
+///     assert_eq!(collection.len(), 2);
+///     collection.clear();
+///     assert!(collection.is_empty());
+/// }
+/// ```
+///
+/// [`LenMut`]: trait.LenMut.html
+pub trait DynLenMut: Clear {
+    /// Truncates the collection to be no greater than `len` long, dropping elements as needed.
+    ///
+    /// This behaves the same as `LenMut::truncate`.
+    fn dyn_truncate(&mut self, len: usize);
+}
+
+impl<T: LenMut> DynLenMut for T {
+    fn dyn_truncate(&mut self, len: usize) {
+        LenMut::truncate(self, len)
+    }
+}
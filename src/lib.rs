@@ -24,22 +24,111 @@
 //! The `len` module provides:
 //!
 //! * [`LenMut`], which requires `Clear`
+//! * [`TryLenMut`], which requires `LenMut`
+//! * [`DynLenMut`], a dyn-compatible `LenMut`, automatically implemented from it
 //! * [`Clear`], which requires `Len`
 //! * [`Len`], which requires `Empty`
 //! * [`Empty`]
+//! * [`LenIn<U>`], for measuring strings in the units from the `unit` module
+//! * [`SizeHint`], automatically implemented from `Len`
 //!
 //! The `capacity` module provides:
 //!
 //! * [`CapacityMut`], which requires `WithCapacity`
+//! * [`TryCapacityMut`], which requires `CapacityMut`
+//! * [`DynCapacityMut`], a dyn-compatible `CapacityMut`, automatically implemented from it
 //! * [`WithCapacity`], which requires `Capacity`
 //! * [`Capacity`], which requires `Len`.
+//! * [`SpareCapacityMut`], which requires `CapacityMut`
+//! * [`BoundedPush<T>`], which requires `MaxCapacity`
+//! * [`MaxCapacity`], which requires `Capacity`
 //!
 //! The `index` module provides:
 //!
-//! * [`IndexRange<Idx>`], automatically implemented from `Index<Idx>`
+//! * [`IndexRange<Idx>`], automatically implemented from `Index<Idx>` for `Slicable` types
 //! * [`IndexRangeMut<Idx>`], automatically implemented from `IndexMut<Idx>`
+//! * [`IndexRangeInclusive<Idx>`], which extends `IndexRange<Idx>` with inclusive ranges
+//! * [`IndexRangeInclusiveMut<Idx>`], which extends `IndexRangeMut<Idx>` with inclusive ranges
+//! * [`Slicable`], for opting in to `IndexRange<Idx>`
 //! * [`SplitAt<Idx>`], which requires `IndexRange<Idx>`
-//! * [`SplitAtMut<Idx>`], which requires `IndexRangeMut<Idx>`
+//! * [`SplitAtMut<Idx>`], which requires `SplitAt<Idx>` and `IndexRangeMut<Idx>`
+//! * [`SplitSlice<Idx>`], for splitting containers which dereference to a slice
+//! * [`SplitSliceMut<Idx>`], which requires `SplitSlice<Idx>`
+//! * [`TrySplitAt`], automatically implemented from `SplitSlice<usize>` when its slices are
+//!   `GetRange`
+//! * [`GetRange`], which requires `IndexRange<usize>` and `Len`
+//! * [`Split`], automatically implemented from `SplitSlice<usize>` when its slices are `SplitStep`
+//! * [`SplitStep`], which requires `SplitSlice<usize>` and `Len`
+//!
+//! The `error` module provides the errors returned by checked methods, like [`IndexError`],
+//! [`ReserveError`] and [`CapacityExceeded`].
+//!
+//! The `array` module provides:
+//!
+//! * [`StaticLen`], which requires `Len`
+//! * [`SplitArray<T>`], automatically implemented from `StaticLen` and `SplitSlice<usize>`
+//! * [`AsArray<T>`], automatically implemented from `SplitSlice<usize>`
+//!
+//! The `atomic` module provides:
+//!
+//! * [`AtomicLen<C>`], a wrapper which mirrors the length of a collection in an atomic integer
+//! * [`LenHandle`], which reads the length of an `AtomicLen<C>` without locking it
+//!
+//! The `bounded` module provides:
+//!
+//! * [`BoundedLen<C, MAX>`], a wrapper which limits the length of a collection
+//!
+//! The `heap` module provides:
+//!
+//! * [`DeepHeapSize`], which requires `HeapSize`
+//! * [`HeapSize`]
+//!
+//! The `io` module provides:
+//!
+//! * [`RemainingLen`], for measuring how much of a buffer is left to be read
+//!
+//! The `policy` module provides:
+//!
+//! * [`GrowthPolicy`], for choosing how much capacity to allocate
+//! * [`WithPolicy<C, P>`], a wrapper which grows a collection according to a `GrowthPolicy`
+//! * [`Exact`], [`Linear<STEP>`], [`CappedExponential<MAX_STEP>`] and
+//!   [`PageRounded<T, PAGE_SIZE>`], the built-in policies
+//!
+//! The `pool` module provides:
+//!
+//! * [`Pool<C>`], a pool of reusable collections for a single thread
+//! * [`SyncPool<C>`], a pool of reusable collections which can be shared between threads
+//! * [`Oversize`], for deciding what happens to collections which have grown too large
+//!
+//! The `tracked` module provides:
+//!
+//! * [`Tracked<C>`], a wrapper which records how the capacity of a collection changes
+//! * [`TrackedStats`], the statistics recorded by `Tracked<C>`
+//!
+//! The `iter` module provides:
+//!
+//! * [`LenIter`], automatically implemented from `Len`
+//! * [`ExactLen<I>`], which implements `Len` for any `ExactSizeIterator`
+//! * [`ExactIter<I>`], an `ExactSizeIterator` over a collection with a `Len`
+//! * [`RangeLen`], for counting the integers in a range without overflowing
+//! * [`ExactRange<R>`], which implements `Len` for a range of integers
+//!
+//! The `non_empty` module provides:
+//!
+//! * [`Ends`], for looking up the first and last elements of a collection
+//! * [`NonEmpty<C>`], a wrapper which guarantees that a collection is never empty
+//!
+//! The `segment` module provides:
+//!
+//! * [`MakeContiguous`], which requires `SplitSegmentsMut`
+//! * [`SplitSegmentsMut`], which requires `SplitSegments`
+//! * [`SplitSegments`], which requires `Len`
+//! * [`Segments`] and [`SegmentsMut`], views of a collection as two slices
+//!
+//! The `string` module provides:
+//!
+//! * [`CharBoundaryMut`], which requires `CharBoundary`
+//! * [`CharBoundary`], which requires `Len`
 //!
 //! # Features
 //!
@@ -47,17 +136,84 @@
 //! collections. The `std` feature automatically enables `alloc`. Although the `std` feature is the
 //! default, disabling it will enable `no_std`.
 //!
+//! The `unicode-segmentation` feature adds the [`Graphemes`] unit, for measuring strings in
+//! extended grapheme clusters.
+//!
+//! The `std` feature also adds the [`io`] module, and implements `Len` for the buffers in
+//! `std::io`.
+//!
+//! The `serde` feature adds the [`serde`] module, with deserialisers which pre-allocate collections
+//! using [`WithCapacity`] without letting the input choose an unbounded capacity.
+//!
 //! [`LenMut`]: len/trait.LenMut.html
+//! [`TryLenMut`]: len/trait.TryLenMut.html
+//! [`DynLenMut`]: len/trait.DynLenMut.html
 //! [`Clear`]: len/trait.Clear.html
 //! [`Len`]: len/trait.Len.html
 //! [`Empty`]: len/trait.Empty.html
+//! [`LenIn<U>`]: len/trait.LenIn.html
+//! [`SizeHint`]: len/trait.SizeHint.html
+//! [`Graphemes`]: unit/enum.Graphemes.html
+//! [`serde`]: serde/index.html
+//! [`io`]: io/index.html
 //! [`CapacityMut`]: capacity/trait.CapacityMut.html
+//! [`TryCapacityMut`]: capacity/trait.TryCapacityMut.html
+//! [`DynCapacityMut`]: capacity/trait.DynCapacityMut.html
 //! [`WithCapacity`]: capacity/trait.WithCapacity.html
 //! [`Capacity`]: capacity/trait.Capacity.html
+//! [`SpareCapacityMut`]: capacity/trait.SpareCapacityMut.html
+//! [`BoundedPush<T>`]: capacity/trait.BoundedPush.html
+//! [`MaxCapacity`]: capacity/trait.MaxCapacity.html
 //! [`SplitAt<Idx>`]: index/trait.SplitAt.html
 //! [`SplitAtMut<Idx>`]: index/trait.SplitAtMut.html
+//! [`SplitSlice<Idx>`]: index/trait.SplitSlice.html
+//! [`SplitSliceMut<Idx>`]: index/trait.SplitSliceMut.html
+//! [`TrySplitAt`]: index/trait.TrySplitAt.html
+//! [`IndexError`]: error/enum.IndexError.html
+//! [`ReserveError`]: error/enum.ReserveError.html
+//! [`CapacityExceeded`]: error/struct.CapacityExceeded.html
 //! [`IndexRange<Idx>`]: index/trait.IndexRange.html
 //! [`IndexRangeMut<Idx>`]: index/trait.IndexRangeMut.html
+//! [`IndexRangeInclusive<Idx>`]: index/trait.IndexRangeInclusive.html
+//! [`IndexRangeInclusiveMut<Idx>`]: index/trait.IndexRangeInclusiveMut.html
+//! [`Slicable`]: index/trait.Slicable.html
+//! [`GetRange`]: index/trait.GetRange.html
+//! [`Split`]: index/trait.Split.html
+//! [`SplitStep`]: index/trait.SplitStep.html
+//! [`StaticLen`]: array/trait.StaticLen.html
+//! [`SplitArray<T>`]: array/trait.SplitArray.html
+//! [`AsArray<T>`]: array/trait.AsArray.html
+//! [`AtomicLen<C>`]: atomic/struct.AtomicLen.html
+//! [`LenHandle`]: atomic/struct.LenHandle.html
+//! [`BoundedLen<C, MAX>`]: bounded/struct.BoundedLen.html
+//! [`DeepHeapSize`]: heap/trait.DeepHeapSize.html
+//! [`HeapSize`]: heap/trait.HeapSize.html
+//! [`RemainingLen`]: io/trait.RemainingLen.html
+//! [`GrowthPolicy`]: policy/trait.GrowthPolicy.html
+//! [`WithPolicy<C, P>`]: policy/struct.WithPolicy.html
+//! [`Exact`]: policy/struct.Exact.html
+//! [`Linear<STEP>`]: policy/struct.Linear.html
+//! [`CappedExponential<MAX_STEP>`]: policy/struct.CappedExponential.html
+//! [`PageRounded<T, PAGE_SIZE>`]: policy/struct.PageRounded.html
+//! [`Pool<C>`]: pool/struct.Pool.html
+//! [`SyncPool<C>`]: pool/struct.SyncPool.html
+//! [`Oversize`]: pool/enum.Oversize.html
+//! [`Tracked<C>`]: tracked/struct.Tracked.html
+//! [`TrackedStats`]: tracked/struct.TrackedStats.html
+//! [`LenIter`]: iter/trait.LenIter.html
+//! [`ExactLen<I>`]: iter/struct.ExactLen.html
+//! [`ExactIter<I>`]: iter/struct.ExactIter.html
+//! [`RangeLen`]: iter/trait.RangeLen.html
+//! [`ExactRange<R>`]: iter/struct.ExactRange.html
+//! [`Ends`]: non_empty/trait.Ends.html
+//! [`NonEmpty<C>`]: non_empty/struct.NonEmpty.html
+//! [`MakeContiguous`]: segment/trait.MakeContiguous.html
+//! [`SplitSegmentsMut`]: segment/trait.SplitSegmentsMut.html
+//! [`SplitSegments`]: segment/trait.SplitSegments.html
+//! [`Segments`]: segment/struct.Segments.html
+//! [`SegmentsMut`]: segment/struct.SegmentsMut.html
+//! [`CharBoundaryMut`]: string/trait.CharBoundaryMut.html
+//! [`CharBoundary`]: string/trait.CharBoundary.html
 //! [`capacity`]: capacity/trait.Capacity.html#tymethod.capacity
 //! [`clear`]: len/trait.Clear.html#tymethod.clear
 //! [`is_empty`]: len/trait.Empty.html#tymethod.is_empty
@@ -78,12 +234,46 @@
 
 extern crate alloc;
 
+#[macro_use]
+mod macros;
+
+pub mod array;
+pub mod atomic;
+pub mod bounded;
 pub mod capacity;
+pub mod error;
+pub mod heap;
 pub mod index;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod iter;
 pub mod len;
+pub mod non_empty;
+pub mod policy;
+pub mod pool;
+pub mod segment;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod string;
+pub mod tracked;
+pub mod unit;
 
+pub use array::*;
+pub use atomic::*;
+pub use bounded::*;
 pub use capacity::*;
+pub use error::*;
+pub use heap::*;
 pub use index::*;
+#[cfg(feature = "std")]
+pub use io::*;
+pub use iter::*;
 pub use len::*;
+pub use non_empty::*;
+pub use policy::*;
+pub use pool::*;
+pub use segment::*;
+pub use string::*;
+pub use tracked::*;
 
 mod impls;
\ No newline at end of file
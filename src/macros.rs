@@ -0,0 +1,87 @@
+/// Implements the traits which can't change the length or capacity of a collection for a wrapper,
+/// by forwarding them to the field which holds the collection.
+///
+/// The wrapped collection's type must be named `C`, and any other generics are given in brackets.
+macro_rules! forward_shared {
+    ([$($gen:tt)*] $wrapper:ty => $field:ident) => {
+        impl<C: $crate::len::Empty, $($gen)*> $crate::len::Empty for $wrapper {
+            fn is_empty(&self) -> bool {
+                self.$field.is_empty()
+            }
+        }
+        impl<C: $crate::len::Len, $($gen)*> $crate::len::Len for $wrapper {
+            fn len(&self) -> usize {
+                self.$field.len()
+            }
+        }
+        impl<C: $crate::array::StaticLen, $($gen)*> $crate::array::StaticLen for $wrapper {
+            const LEN: usize = C::LEN;
+        }
+        impl<U, C: $crate::len::LenIn<U>, $($gen)*> $crate::len::LenIn<U> for $wrapper {
+            fn len_in(&self) -> usize {
+                self.$field.len_in()
+            }
+        }
+        impl<C: $crate::heap::HeapSize, $($gen)*> $crate::heap::HeapSize for $wrapper {
+            fn heap_reserved(&self) -> usize {
+                self.$field.heap_reserved()
+            }
+            fn heap_used(&self) -> usize {
+                self.$field.heap_used()
+            }
+        }
+        impl<C: $crate::heap::DeepHeapSize, $($gen)*> $crate::heap::DeepHeapSize for $wrapper {
+            fn deep_heap_reserved(&self) -> usize {
+                self.$field.deep_heap_reserved()
+            }
+            fn deep_heap_used(&self) -> usize {
+                self.$field.deep_heap_used()
+            }
+        }
+        impl<Idx, C: $crate::index::SplitSlice<Idx>, $($gen)*> $crate::index::SplitSlice<Idx>
+            for $wrapper {
+            type Slice = C::Slice;
+            fn split_slice(&self, index: Idx) -> (&C::Slice, &C::Slice) {
+                self.$field.split_slice(index)
+            }
+        }
+        impl<Idx, C: $crate::index::SplitSliceMut<Idx>, $($gen)*>
+            $crate::index::SplitSliceMut<Idx> for $wrapper {
+            fn split_slice_mut(&mut self, index: Idx) -> (&mut C::Slice, &mut C::Slice) {
+                self.$field.split_slice_mut(index)
+            }
+        }
+        impl<C: $crate::string::CharBoundary, $($gen)*> $crate::string::CharBoundary
+            for $wrapper {
+            type Slice = C::Slice;
+            fn is_char_boundary(&self, index: usize) -> bool {
+                self.$field.is_char_boundary(index)
+            }
+            fn split_at_floor(&self, index: usize) -> (&C::Slice, &C::Slice) {
+                self.$field.split_at_floor(index)
+            }
+            fn split_at_ceil(&self, index: usize) -> (&C::Slice, &C::Slice) {
+                self.$field.split_at_ceil(index)
+            }
+        }
+        impl<C: $crate::segment::SplitSegments, $($gen)*> $crate::segment::SplitSegments
+            for $wrapper {
+            type Slice = C::Slice;
+            fn as_segments(&self) -> $crate::segment::Segments<'_, C::Slice> {
+                self.$field.as_segments()
+            }
+        }
+        impl<C: $crate::segment::SplitSegmentsMut, $($gen)*> $crate::segment::SplitSegmentsMut
+            for $wrapper {
+            fn as_segments_mut(&mut self) -> $crate::segment::SegmentsMut<'_, C::Slice> {
+                self.$field.as_segments_mut()
+            }
+        }
+        impl<C: $crate::segment::MakeContiguous, $($gen)*> $crate::segment::MakeContiguous
+            for $wrapper {
+            fn make_contiguous(&mut self) -> &mut C::Slice {
+                self.$field.make_contiguous()
+            }
+        }
+    };
+}
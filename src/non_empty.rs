@@ -0,0 +1,281 @@
+//! Wrappers which guarantee that a collection is never empty.
+use std::num::NonZeroUsize;
+use std::ops::Deref;
+
+use super::capacity::{BoundedPush, Capacity, CapacityMut, MaxCapacity};
+use super::error::CapacityExceeded;
+use super::len::{Empty, Len, LenMut};
+
+/// A trait for collections whose first and last elements can be looked up.
+///
+/// This covers slices and the types which dereference to them, as well as `VecDeque`,
+/// `LinkedList` and `BTreeSet`. For sets, the first and last elements are the smallest and
+/// largest ones.
+///
+/// The methods are named `first_item` and `last_item` so that calling them is never ambiguous
+/// with the inherent `first` and `last` methods of slices and `BTreeSet`.
+///
+/// Looking up the first and last elements must take a constant amount of time and space, or
+/// logarithmic for ordered sets.
+pub trait Ends: Len {
+    /// The type of the elements.
+    type Item;
+
+    /// Returns the first element of the collection, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use len_trait::Ends;
+    ///
+    /// let deque: VecDeque<_> = (1..=3).collect();
+    /// assert_eq!(deque.first_item(), Some(&1));
+    /// assert_eq!(Vec::<u8>::new().first_item(), None);
+    /// ```
+    fn first_item(&self) -> Option<&Self::Item>;
+
+    /// Returns the last element of the collection, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::LinkedList;
+    /// use len_trait::Ends;
+    ///
+    /// let list: LinkedList<_> = (1..=3).collect();
+    /// assert_eq!(list.last_item(), Some(&3));
+    /// assert_eq!([0u8; 0].last_item(), None);
+    /// ```
+    fn last_item(&self) -> Option<&Self::Item>;
+}
+
+/// A wrapper which guarantees that a collection always has at least one element.
+///
+/// The only way to create a `NonEmpty` is by checking a collection with [`NonEmpty::new`], and it
+/// offers no way of removing every element: it doesn't implement `Clear` or `LenMut`, and its
+/// [`truncate`] and [`split_off`] methods take a `NonZeroUsize`. In exchange, methods like
+/// [`first`] and [`len`] don't have to account for the empty case.
+///
+/// The wrapped collection can be read through `Deref`, but can only be modified through the
+/// wrapper.
+///
+/// # Examples
+///
+/// ```rust
+/// use len_trait::NonEmpty;
+///
+/// let args = NonEmpty::new(vec!["program", "--verbose"]).unwrap();
+/// assert_eq!(*args.first(), "program");
+/// assert_eq!(args.len().get(), 2);
+///
+/// assert_eq!(NonEmpty::new(Vec::<u8>::new()), Err(vec![]));
+/// ```
+///
+/// [`NonEmpty::new`]: #method.new
+/// [`truncate`]: #method.truncate
+/// [`split_off`]: #method.split_off
+/// [`first`]: #method.first
+/// [`len`]: #method.len
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NonEmpty<C> {
+    inner: C,
+}
+
+impl<C: Empty> NonEmpty<C> {
+    /// Wraps a collection, returning it as an error if it is empty.
+    pub fn new(inner: C) -> Result<Self, C> {
+        if inner.is_empty() {
+            Err(inner)
+        } else {
+            Ok(NonEmpty { inner })
+        }
+    }
+
+    /// Unwraps the collection.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C: Len> NonEmpty<C> {
+    /// Returns the length of the collection, which is never zero.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.inner.len()).expect("NonEmpty collection was empty")
+    }
+}
+
+impl<C: LenMut> NonEmpty<C> {
+    /// Truncates the collection to be no greater than `len` long, dropping elements as needed.
+    ///
+    /// If the collection is less than `len` long, do nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is not valid according to the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::NonZeroUsize;
+    /// use len_trait::NonEmpty;
+    ///
+    /// let mut vec = NonEmpty::new(vec![1, 2, 3]).unwrap();
+    /// vec.truncate(NonZeroUsize::new(1).unwrap());
+    /// assert_eq!(*vec, [1]);
+    /// ```
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        self.inner.truncate(len.get())
+    }
+
+    /// Splits off the collection at the given index, returning the data past the index.
+    ///
+    /// The data past the index may be empty, so it isn't wrapped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::NonZeroUsize;
+    /// use len_trait::NonEmpty;
+    ///
+    /// let mut vec = NonEmpty::new(vec![1, 2, 3]).unwrap();
+    /// assert_eq!(vec.split_off(NonZeroUsize::new(3).unwrap()), []);
+    /// assert_eq!(vec.split_off(NonZeroUsize::new(1).unwrap()), [2, 3]);
+    /// assert_eq!(*vec, [1]);
+    /// ```
+    pub fn split_off(&mut self, index: NonZeroUsize) -> C {
+        self.inner.split_off(index.get())
+    }
+}
+
+impl<C: CapacityMut> NonEmpty<C> {
+    /// Ensures that the capacity is at least the current length plus `additional`.
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+
+    /// Similar to `reserve`, adding a strong hint to not reserve capacity above what's needed.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.inner.reserve_exact(additional)
+    }
+
+    /// Reduces the capacity down as close as possible to the current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+}
+
+impl<C: Ends> NonEmpty<C> {
+    /// Returns the first element of the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::{BTreeSet, LinkedList, VecDeque};
+    /// use len_trait::NonEmpty;
+    ///
+    /// let deque: VecDeque<_> = (1..=3).collect();
+    /// assert_eq!(*NonEmpty::new(deque).unwrap().first(), 1);
+    ///
+    /// let set: BTreeSet<_> = [3, 1, 2].into_iter().collect();
+    /// assert_eq!(*NonEmpty::new(set).unwrap().first(), 1);
+    ///
+    /// let list: LinkedList<_> = (1..=3).collect();
+    /// assert_eq!(*NonEmpty::new(list).unwrap().first(), 1);
+    /// ```
+    pub fn first(&self) -> &C::Item {
+        self.inner.first_item().expect("NonEmpty collection was empty")
+    }
+
+    /// Returns the last element of the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeSet;
+    /// use len_trait::NonEmpty;
+    ///
+    /// let boxed: Box<[u8]> = Box::new([1, 2, 3]);
+    /// assert_eq!(*NonEmpty::new(boxed).unwrap().last(), 3);
+    ///
+    /// let set: BTreeSet<_> = [3, 1, 2].into_iter().collect();
+    /// assert_eq!(*NonEmpty::new(set).unwrap().last(), 3);
+    /// ```
+    pub fn last(&self) -> &C::Item {
+        self.inner.last_item().expect("NonEmpty collection was empty")
+    }
+}
+
+impl<C: Deref<Target = str>> NonEmpty<C> {
+    /// Returns the first character of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::NonEmpty;
+    ///
+    /// let name = NonEmpty::new("Émile".to_string()).unwrap();
+    /// assert_eq!(name.first_char(), 'É');
+    /// ```
+    pub fn first_char(&self) -> char {
+        self.inner.chars().next().expect("NonEmpty string was empty")
+    }
+
+    /// Returns the last character of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::NonEmpty;
+    ///
+    /// let name = NonEmpty::new("Zoë".to_string()).unwrap();
+    /// assert_eq!(name.last_char(), 'ë');
+    /// ```
+    pub fn last_char(&self) -> char {
+        self.inner.chars().next_back().expect("NonEmpty string was empty")
+    }
+}
+
+impl<C> Deref for NonEmpty<C> {
+    type Target = C;
+    fn deref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C> AsRef<C> for NonEmpty<C> {
+    fn as_ref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<T, C: Extend<T>> Extend<T> for NonEmpty<C> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter)
+    }
+}
+
+forward_shared!([] NonEmpty<C> => inner);
+
+impl<C: Capacity> Capacity for NonEmpty<C> {
+    fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+}
+
+impl<C: MaxCapacity> MaxCapacity for NonEmpty<C> {
+    fn max_capacity(&self) -> usize {
+        self.inner.max_capacity()
+    }
+}
+
+impl<T, C: BoundedPush<T>> BoundedPush<T> for NonEmpty<C> {
+    fn try_push(&mut self, value: T) -> Result<(), CapacityExceeded<T>> {
+        self.inner.try_push(value)
+    }
+}
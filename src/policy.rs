@@ -0,0 +1,354 @@
+//! Policies which decide how much a collection's capacity grows.
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::mem::MaybeUninit;
+use std::ops::Deref;
+
+use super::capacity::{BoundedPush, Capacity, CapacityMut, MaxCapacity, SpareCapacityMut,
+                      TryCapacityMut, WithCapacity};
+use super::error::{CapacityExceeded, CapacityOverflow, IndexError, ReserveError};
+use super::len::{Clear, LenMut, TryLenMut};
+use super::string::CharBoundaryMut;
+
+/// A trait for deciding how much capacity to allocate when a collection has to grow.
+///
+/// This is used by [`WithPolicy`] in place of a collection's own growth strategy, which is
+/// usually to double its capacity.
+///
+/// Choosing a new capacity must take a constant amount of time and space.
+///
+/// [`WithPolicy`]: struct.WithPolicy.html
+pub trait GrowthPolicy {
+    /// Returns the capacity to grow to, given the current capacity and the capacity which is
+    /// required, which is always larger.
+    ///
+    /// Returning less than `required` is allowed, but `required` will be used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::{CappedExponential, Exact, GrowthPolicy, Linear, PageRounded};
+    ///
+    /// assert_eq!(Exact.grow(10, 11), 11);
+    /// assert_eq!(Linear::<8>.grow(10, 11), 18);
+    /// assert_eq!(CappedExponential::<4>.grow(10, 11), 14);
+    /// assert_eq!(PageRounded::<u32, 64>::new().grow(10, 11), 16);
+    /// ```
+    fn grow(&self, capacity: usize, required: usize) -> usize;
+}
+
+/// A policy which grows a collection to exactly the capacity required.
+///
+/// This minimises memory usage, but makes pushing elements one at a time take quadratic time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Exact;
+
+impl GrowthPolicy for Exact {
+    fn grow(&self, _capacity: usize, required: usize) -> usize {
+        required
+    }
+}
+
+/// A policy which grows a collection by a whole number of steps of `STEP` elements.
+///
+/// This keeps the amount of wasted capacity below one step, and makes the cost of each
+/// reallocation predictable, at the cost of reallocating more often as the collection grows. A
+/// `STEP` of zero behaves like [`Exact`].
+///
+/// # Examples
+///
+/// ```rust
+/// use len_trait::{GrowthPolicy, Linear};
+///
+/// assert_eq!(Linear::<8>.grow(10, 27), 34);
+///
+/// // The capacity never shrinks, even if less is required.
+/// assert_eq!(Linear::<8>.grow(10, 4), 10);
+/// ```
+///
+/// [`Exact`]: struct.Exact.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Linear<const STEP: usize>;
+
+impl<const STEP: usize> GrowthPolicy for Linear<STEP> {
+    fn grow(&self, capacity: usize, required: usize) -> usize {
+        let step = STEP.max(1);
+        let steps = required.saturating_sub(capacity).div_ceil(step);
+        capacity.saturating_add(steps.saturating_mul(step))
+    }
+}
+
+/// A policy which doubles the capacity of a collection, but never grows it by more than
+/// `MAX_STEP` elements at once, unless more are required.
+///
+/// This behaves like the usual amortised doubling for small collections, while limiting how much
+/// capacity large collections can waste and how long each reallocation takes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CappedExponential<const MAX_STEP: usize>;
+
+impl<const MAX_STEP: usize> GrowthPolicy for CappedExponential<MAX_STEP> {
+    fn grow(&self, capacity: usize, _required: usize) -> usize {
+        capacity.saturating_add(capacity.clamp(1, MAX_STEP.max(1)))
+    }
+}
+
+/// A policy which rounds the size of a collection of `T` up to a whole number of pages of
+/// `PAGE_SIZE` bytes.
+///
+/// The required capacity is converted to bytes, rounded up to whole pages, and converted back to
+/// as many elements as fit in those pages. This avoids leaving part of a page unused when
+/// collections are large enough to be allocated directly from the operating system: less than one
+/// element's worth of space is wasted, even when elements don't divide the page size evenly.
+///
+/// # Examples
+///
+/// ```rust
+/// use len_trait::{GrowthPolicy, PageRounded};
+///
+/// // Three 24-byte elements take 72 bytes, or two pages, which fit five elements.
+/// assert_eq!(PageRounded::<[u8; 24], 64>::new().grow(0, 3), 5);
+///
+/// // Elements larger than a page still fill whole pages.
+/// assert_eq!(PageRounded::<[u8; 96], 64>::new().grow(0, 1), 1);
+/// assert_eq!(PageRounded::<[u8; 96], 64>::new().grow(1, 2), 2);
+/// ```
+pub struct PageRounded<T, const PAGE_SIZE: usize = 4096> {
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T, const PAGE_SIZE: usize> PageRounded<T, PAGE_SIZE> {
+    /// Creates the policy.
+    pub fn new() -> Self {
+        PageRounded { marker: PhantomData }
+    }
+}
+
+impl<T, const PAGE_SIZE: usize> GrowthPolicy for PageRounded<T, PAGE_SIZE> {
+    fn grow(&self, _capacity: usize, required: usize) -> usize {
+        let size = mem::size_of::<T>();
+        if size == 0 {
+            return required;
+        }
+        let page_size = PAGE_SIZE.max(1);
+        let pages = required.saturating_mul(size).div_ceil(page_size);
+        pages.saturating_mul(page_size) / size
+    }
+}
+
+// These are implemented manually so that they don't require `T` to implement them.
+impl<T, const PAGE_SIZE: usize> Clone for PageRounded<T, PAGE_SIZE> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T, const PAGE_SIZE: usize> Copy for PageRounded<T, PAGE_SIZE> {}
+impl<T, const PAGE_SIZE: usize> Default for PageRounded<T, PAGE_SIZE> {
+    fn default() -> Self {
+        PageRounded::new()
+    }
+}
+impl<T, const PAGE_SIZE: usize> fmt::Debug for PageRounded<T, PAGE_SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PageRounded").field("page_size", &PAGE_SIZE).finish()
+    }
+}
+
+/// A wrapper which grows a collection according to a [`GrowthPolicy`].
+///
+/// Calls to `CapacityMut::reserve`, and elements added with `Extend`, are translated into calls
+/// to `reserve_exact` with the capacity chosen by the policy. Calls to `reserve_exact` are
+/// forwarded unchanged.
+///
+/// The wrapped collection can be read through `Deref`, but can only be modified through the
+/// wrapper, so that the policy can't be bypassed.
+///
+/// # Examples
+///
+/// ```rust
+/// use len_trait::{Capacity, CapacityMut, Linear, WithPolicy};
+///
+/// let mut vec = WithPolicy::new(Vec::<u8>::new(), Linear::<16>);
+/// vec.reserve(1);
+/// assert_eq!(vec.capacity(), 16);
+///
+/// vec.extend(0..20);
+/// assert_eq!(vec.capacity(), 32);
+///
+/// vec.reserve(40);
+/// assert_eq!(vec.capacity(), 64);
+/// ```
+///
+/// ```rust
+/// use len_trait::{Capacity, CappedExponential, PageRounded, WithPolicy};
+///
+/// let mut vec = WithPolicy::new(Vec::<u64>::new(), CappedExponential::<1000>);
+/// for i in 0..5000 {
+///     vec.extend(Some(i));
+/// }
+/// assert_eq!(vec.capacity(), 5024);
+///
+/// let mut vec = WithPolicy::new(Vec::<u64>::new(), PageRounded::<u64>::new());
+/// vec.extend(0..1000);
+/// assert_eq!(vec.capacity(), 1024);
+/// ```
+///
+/// [`GrowthPolicy`]: trait.GrowthPolicy.html
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WithPolicy<C, P> {
+    inner: C,
+    policy: P,
+}
+
+impl<C, P> WithPolicy<C, P> {
+    /// Wraps a collection, growing it according to the given policy.
+    pub fn new(inner: C, policy: P) -> Self {
+        WithPolicy { inner, policy }
+    }
+
+    /// Returns the policy used to grow the collection.
+    pub fn policy(&self) -> &P {
+        &self.policy
+    }
+
+    /// Unwraps the collection.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C: CapacityMut, P: GrowthPolicy> WithPolicy<C, P> {
+    /// Ensures that the capacity is at least the current length plus `additional`, growing it
+    /// according to the policy.
+    fn grow(&mut self, additional: usize) {
+        let len = self.inner.len();
+        let capacity = self.inner.capacity();
+        let required = len.checked_add(additional).expect("capacity overflow");
+        if required > capacity {
+            let target = self.policy.grow(capacity, required).max(required);
+            self.inner.reserve_exact(target - len)
+        }
+    }
+}
+
+impl<C, P> Deref for WithPolicy<C, P> {
+    type Target = C;
+    fn deref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C, P> AsRef<C> for WithPolicy<C, P> {
+    fn as_ref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<T, C, P> Extend<T> for WithPolicy<C, P>
+where
+    C: CapacityMut + Extend<T>,
+    P: GrowthPolicy,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.grow(iter.size_hint().0);
+        for value in iter {
+            if self.inner.len() == self.inner.capacity() {
+                self.grow(1);
+            }
+            self.inner.extend(Some(value));
+        }
+    }
+}
+
+forward_shared!([P] WithPolicy<C, P> => inner);
+
+impl<C: Clear, P> Clear for WithPolicy<C, P> {
+    fn clear(&mut self) {
+        self.inner.clear()
+    }
+}
+
+impl<C: LenMut, P: Clone + Default> LenMut for WithPolicy<C, P> {
+    fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len)
+    }
+    fn split_off(&mut self, index: usize) -> Self {
+        WithPolicy::new(self.inner.split_off(index), self.policy.clone())
+    }
+}
+
+impl<C: TryLenMut, P: Clone + Default> TryLenMut for WithPolicy<C, P> {
+    fn check_boundary(&self, index: usize) -> Result<(), IndexError> {
+        self.inner.check_boundary(index)
+    }
+}
+
+impl<C: CharBoundaryMut, P> CharBoundaryMut for WithPolicy<C, P> {
+    fn truncate_floor(&mut self, len: usize) {
+        self.inner.truncate_floor(len)
+    }
+}
+
+impl<C: Capacity, P> Capacity for WithPolicy<C, P> {
+    fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+}
+
+impl<C: WithCapacity, P: Default> WithCapacity for WithPolicy<C, P> {
+    fn with_capacity(capacity: usize) -> Self {
+        WithPolicy::new(C::with_capacity(capacity), P::default())
+    }
+}
+
+impl<C: CapacityMut, P: GrowthPolicy + Default> CapacityMut for WithPolicy<C, P> {
+    fn reserve(&mut self, additional: usize) {
+        self.grow(additional)
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        self.inner.reserve_exact(additional)
+    }
+    fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+}
+
+impl<C: TryCapacityMut, P: GrowthPolicy + Default> TryCapacityMut for WithPolicy<C, P> {
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+        let len = self.inner.len();
+        let capacity = self.inner.capacity();
+        let required = len.checked_add(additional).ok_or(CapacityOverflow)?;
+        if required > capacity {
+            let target = self.policy.grow(capacity, required).max(required);
+            self.inner.try_reserve_exact(target - len)?;
+        }
+        Ok(())
+    }
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), ReserveError> {
+        self.inner.try_reserve_exact(additional)
+    }
+}
+
+impl<C: SpareCapacityMut, P: GrowthPolicy + Default> SpareCapacityMut for WithPolicy<C, P> {
+    type Element = C::Element;
+    fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<C::Element>] {
+        self.inner.spare_capacity_mut()
+    }
+    unsafe fn set_len(&mut self, len: usize) {
+        // SAFETY: the caller upholds the same contract for the wrapped collection.
+        unsafe { self.inner.set_len(len) }
+    }
+}
+
+impl<C: MaxCapacity, P> MaxCapacity for WithPolicy<C, P> {
+    fn max_capacity(&self) -> usize {
+        self.inner.max_capacity()
+    }
+}
+
+impl<T, C: BoundedPush<T>, P> BoundedPush<T> for WithPolicy<C, P> {
+    fn try_push(&mut self, value: T) -> Result<(), CapacityExceeded<T>> {
+        self.inner.try_push(value)
+    }
+}
@@ -0,0 +1,327 @@
+//! Pools of collections which can be reused without reallocating.
+use std::cell::RefCell;
+use std::fmt;
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Mutex, PoisonError};
+
+use super::capacity::CapacityMut;
+use super::len::Clear;
+
+/// What a pool does with a returned collection whose capacity exceeds the pool's limit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Oversize {
+    /// Drops the collection, freeing its memory.
+    Discard,
+
+    /// Keeps the collection, but reallocates it with `CapacityMut::shrink_to_fit` and
+    /// `CapacityMut::reserve_exact` so that its capacity is as close to the limit as the
+    /// collection allows without exceeding it.
+    ///
+    /// If the collection can't be brought under the limit, it is dropped instead.
+    Shrink,
+}
+
+/// Clears a collection so that it can be reused, or returns `None` if it should be dropped.
+fn recycle<C>(mut item: C, max_capacity: usize, oversize: Oversize) -> Option<C>
+where
+    C: Clear + CapacityMut,
+{
+    item.clear();
+    if item.capacity() > max_capacity {
+        match oversize {
+            Oversize::Discard => return None,
+            Oversize::Shrink => {
+                item.shrink_to_fit();
+                item.reserve_exact(max_capacity);
+                if item.capacity() > max_capacity {
+                    // Some collections, like `HashMap`, can't allocate exactly the limit.
+                    item.shrink_to_fit();
+                }
+                if item.capacity() > max_capacity {
+                    return None;
+                }
+            }
+        }
+    }
+    Some(item)
+}
+
+/// A pool of collections which can be reused, for use on a single thread.
+///
+/// Collections are taken from the pool with [`get`], which creates a new one with `Default` if
+/// none are available. When the returned guard is dropped, the collection is cleared with
+/// `Clear::clear` and put back into the pool, keeping its capacity so that it doesn't have to
+/// allocate again. If its capacity exceeds the pool's limit, it is handled according to the
+/// pool's [`Oversize`] setting instead.
+///
+/// See [`SyncPool`] for a pool which can be shared between threads.
+///
+/// # Examples
+///
+/// ```rust
+/// use len_trait::{Oversize, Pool};
+///
+/// let pool: Pool<String> = Pool::new(1024, Oversize::Discard);
+/// {
+///     let mut buffer = pool.get();
+///     buffer.push_str("Hello, world!");
+/// }
+/// assert_eq!(pool.available(), 1);
+///
+/// let buffer = pool.get();
+/// assert!(buffer.is_empty());
+/// assert!(buffer.capacity() >= 13);
+/// ```
+///
+/// [`get`]: #method.get
+/// [`Oversize`]: enum.Oversize.html
+/// [`SyncPool`]: struct.SyncPool.html
+pub struct Pool<C> {
+    items: RefCell<Vec<C>>,
+    max_capacity: usize,
+    oversize: Oversize,
+}
+
+impl<C: Clear + CapacityMut> Pool<C> {
+    /// Creates an empty pool, which handles collections with a capacity above `max_capacity`
+    /// according to `oversize`.
+    pub fn new(max_capacity: usize, oversize: Oversize) -> Self {
+        Pool { items: RefCell::new(Vec::new()), max_capacity, oversize }
+    }
+
+    /// Takes a collection from the pool, or creates a new one if none are available.
+    pub fn get(&self) -> PoolGuard<'_, C> {
+        let item = self.items.borrow_mut().pop().unwrap_or_default();
+        PoolGuard { pool: self, item: ManuallyDrop::new(item) }
+    }
+
+    /// Clears a collection and adds it to the pool, unless it is discarded for being too large.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::{Oversize, Pool};
+    ///
+    /// let pool = Pool::new(16, Oversize::Discard);
+    /// pool.put(Vec::<u8>::with_capacity(8));
+    /// pool.put(Vec::<u8>::with_capacity(32));
+    /// assert_eq!(pool.available(), 1);
+    ///
+    /// let pool = Pool::new(16, Oversize::Shrink);
+    /// pool.put(Vec::<u8>::with_capacity(32));
+    /// assert_eq!(pool.get().capacity(), 16);
+    /// ```
+    ///
+    /// Collections which can't hold exactly the limit are kept below it:
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use len_trait::{Capacity, Oversize, Pool};
+    ///
+    /// let pool = Pool::new(64, Oversize::Shrink);
+    /// pool.put(HashMap::<u32, u32>::with_capacity(1000));
+    /// for _ in 0..2 {
+    ///     let mut map = pool.get();
+    ///     assert!(map.capacity() <= 64);
+    ///     map.extend((0..1000).map(|i| (i, i)));
+    /// }
+    /// assert_eq!(pool.available(), 1);
+    /// assert!(Capacity::capacity(&*pool.get()) <= 64);
+    /// ```
+    pub fn put(&self, item: C) {
+        if let Some(item) = recycle(item, self.max_capacity, self.oversize) {
+            self.items.borrow_mut().push(item);
+        }
+    }
+
+    /// Returns the number of collections waiting in the pool.
+    pub fn available(&self) -> usize {
+        self.items.borrow().len()
+    }
+}
+
+impl<C> fmt::Debug for Pool<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Pool")
+            .field("available", &self.items.borrow().len())
+            .field("max_capacity", &self.max_capacity)
+            .field("oversize", &self.oversize)
+            .finish()
+    }
+}
+
+/// A collection taken from a [`Pool`], which is returned to the pool when dropped.
+///
+/// [`Pool`]: struct.Pool.html
+pub struct PoolGuard<'a, C: Clear + CapacityMut> {
+    pool: &'a Pool<C>,
+    item: ManuallyDrop<C>,
+}
+
+impl<C: Clear + CapacityMut> PoolGuard<'_, C> {
+    /// Takes the collection out of the guard, so that it isn't returned to the pool.
+    ///
+    /// This is an associated function so that it doesn't conflict with the collection's methods.
+    pub fn into_inner(guard: Self) -> C {
+        let mut guard = ManuallyDrop::new(guard);
+        // SAFETY: the guard is never used again, so the item can't be taken twice.
+        unsafe { ManuallyDrop::take(&mut guard.item) }
+    }
+}
+
+impl<C: Clear + CapacityMut> Deref for PoolGuard<'_, C> {
+    type Target = C;
+    fn deref(&self) -> &C {
+        &self.item
+    }
+}
+
+impl<C: Clear + CapacityMut> DerefMut for PoolGuard<'_, C> {
+    fn deref_mut(&mut self) -> &mut C {
+        &mut self.item
+    }
+}
+
+impl<C: Clear + CapacityMut + fmt::Debug> fmt::Debug for PoolGuard<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        C::fmt(&self.item, f)
+    }
+}
+
+impl<C: Clear + CapacityMut> Drop for PoolGuard<'_, C> {
+    fn drop(&mut self) {
+        // SAFETY: the item is never used again after being taken.
+        let item = unsafe { ManuallyDrop::take(&mut self.item) };
+        self.pool.put(item);
+    }
+}
+
+/// A pool of collections which can be reused, shared between threads.
+///
+/// This behaves the same as [`Pool`], except that the collections are kept behind a `Mutex`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use std::sync::Arc;
+/// use std::thread;
+/// use len_trait::{Oversize, SyncPool};
+///
+/// let pool: Arc<SyncPool<HashMap<u32, u32>>> = Arc::new(SyncPool::new(64, Oversize::Shrink));
+/// let handles: Vec<_> = (0..4)
+///     .map(|i| {
+///         let pool = Arc::clone(&pool);
+///         thread::spawn(move || {
+///             let mut map = pool.get();
+///             map.insert(i, i * 2);
+///             map.len()
+///         })
+///     })
+///     .collect();
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), 1);
+/// }
+/// assert!(pool.available() >= 1);
+/// ```
+///
+/// [`Pool`]: struct.Pool.html
+pub struct SyncPool<C> {
+    items: Mutex<Vec<C>>,
+    max_capacity: usize,
+    oversize: Oversize,
+}
+
+impl<C: Clear + CapacityMut> SyncPool<C> {
+    /// Creates an empty pool, which handles collections with a capacity above `max_capacity`
+    /// according to `oversize`.
+    pub fn new(max_capacity: usize, oversize: Oversize) -> Self {
+        SyncPool { items: Mutex::new(Vec::new()), max_capacity, oversize }
+    }
+
+    /// Takes a collection from the pool, or creates a new one if none are available.
+    pub fn get(&self) -> SyncPoolGuard<'_, C> {
+        let item = self.lock().pop().unwrap_or_default();
+        SyncPoolGuard { pool: self, item: ManuallyDrop::new(item) }
+    }
+
+    /// Clears a collection and adds it to the pool, unless it is discarded for being too large.
+    ///
+    /// The collection is cleared before the pool is locked.
+    pub fn put(&self, item: C) {
+        if let Some(item) = recycle(item, self.max_capacity, self.oversize) {
+            self.lock().push(item);
+        }
+    }
+
+    /// Returns the number of collections waiting in the pool.
+    pub fn available(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Locks the pool, ignoring poisoning: the collections in it are always cleared, so a panic
+    /// can't leave them in an inconsistent state.
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<C>> {
+        self.items.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<C> fmt::Debug for SyncPool<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("SyncPool");
+        if let Ok(items) = self.items.try_lock() {
+            debug.field("available", &items.len());
+        }
+        debug
+            .field("max_capacity", &self.max_capacity)
+            .field("oversize", &self.oversize)
+            .finish()
+    }
+}
+
+/// A collection taken from a [`SyncPool`], which is returned to the pool when dropped.
+///
+/// [`SyncPool`]: struct.SyncPool.html
+pub struct SyncPoolGuard<'a, C: Clear + CapacityMut> {
+    pool: &'a SyncPool<C>,
+    item: ManuallyDrop<C>,
+}
+
+impl<C: Clear + CapacityMut> SyncPoolGuard<'_, C> {
+    /// Takes the collection out of the guard, so that it isn't returned to the pool.
+    ///
+    /// This is an associated function so that it doesn't conflict with the collection's methods.
+    pub fn into_inner(guard: Self) -> C {
+        let mut guard = ManuallyDrop::new(guard);
+        // SAFETY: the guard is never used again, so the item can't be taken twice.
+        unsafe { ManuallyDrop::take(&mut guard.item) }
+    }
+}
+
+impl<C: Clear + CapacityMut> Deref for SyncPoolGuard<'_, C> {
+    type Target = C;
+    fn deref(&self) -> &C {
+        &self.item
+    }
+}
+
+impl<C: Clear + CapacityMut> DerefMut for SyncPoolGuard<'_, C> {
+    fn deref_mut(&mut self) -> &mut C {
+        &mut self.item
+    }
+}
+
+impl<C: Clear + CapacityMut + fmt::Debug> fmt::Debug for SyncPoolGuard<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        C::fmt(&self.item, f)
+    }
+}
+
+impl<C: Clear + CapacityMut> Drop for SyncPoolGuard<'_, C> {
+    fn drop(&mut self) {
+        // SAFETY: the item is never used again after being taken.
+        let item = unsafe { ManuallyDrop::take(&mut self.item) };
+        self.pool.put(item);
+    }
+}
@@ -0,0 +1,278 @@
+//! Traits involving collections which store their contents in two separate slices.
+use std::fmt;
+
+use super::index::{SplitSlice, SplitSliceMut};
+use super::len::{Empty, Len};
+
+/// A view of a collection as two slices, which hold its contents in order.
+///
+/// This is how ring buffers like `VecDeque` are laid out in memory: the `front` slice holds the
+/// start of the collection, and the `back` slice holds the rest, wrapping around to the start of
+/// the buffer. Contiguous collections simply have an empty `back`.
+pub struct Segments<'a, S: ?Sized> {
+    /// The first part of the collection.
+    pub front: &'a S,
+
+    /// The last part of the collection.
+    pub back: &'a S,
+}
+
+impl<'a, S: ?Sized> Segments<'a, S> {
+    /// Creates a view from its two slices.
+    pub fn new(front: &'a S, back: &'a S) -> Self {
+        Segments { front, back }
+    }
+}
+
+impl<'a, S: ?Sized + SplitSlice<usize, Slice = S> + Len> Segments<'a, S> {
+    /// Splits the view into two views at the given logical index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the total length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::Segments;
+    ///
+    /// let segments = Segments::new(&[1, 2, 3][..], &[4, 5][..]);
+    /// let (left, right) = segments.split_at(4);
+    /// assert_eq!((left.front, left.back), (&[1, 2, 3][..], &[4][..]));
+    /// assert_eq!((right.front, right.back), (&[5][..], &[][..]));
+    /// ```
+    pub fn split_at(self, index: usize) -> (Self, Self) {
+        let front_len = self.front.len();
+        if index <= front_len {
+            let (left, right) = self.front.split_slice(index);
+            let empty = self.back.split_slice(0).0;
+            (Segments::new(left, empty), Segments::new(right, self.back))
+        } else {
+            let (left, right) = self.back.split_slice(index - front_len);
+            let empty = self.back.split_slice(0).0;
+            (Segments::new(self.front, left), Segments::new(right, empty))
+        }
+    }
+}
+
+impl<'a, S: ?Sized> Clone for Segments<'a, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, S: ?Sized> Copy for Segments<'a, S> {}
+
+impl<'a, S: ?Sized + fmt::Debug> fmt::Debug for Segments<'a, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Segments")
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish()
+    }
+}
+
+impl<'a, S: ?Sized + Empty> Empty for Segments<'a, S> {
+    fn is_empty(&self) -> bool {
+        self.front.is_empty() && self.back.is_empty()
+    }
+}
+impl<'a, S: ?Sized + Len> Len for Segments<'a, S> {
+    fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+}
+
+/// A mutable view of a collection as two slices, which hold its contents in order.
+///
+/// This is the mutable counterpart of [`Segments`].
+///
+/// [`Segments`]: struct.Segments.html
+pub struct SegmentsMut<'a, S: ?Sized> {
+    /// The first part of the collection.
+    pub front: &'a mut S,
+
+    /// The last part of the collection.
+    pub back: &'a mut S,
+}
+
+impl<'a, S: ?Sized> SegmentsMut<'a, S> {
+    /// Creates a view from its two slices.
+    pub fn new(front: &'a mut S, back: &'a mut S) -> Self {
+        SegmentsMut { front, back }
+    }
+}
+
+impl<'a, S: ?Sized + SplitSliceMut<usize, Slice = S> + Len> SegmentsMut<'a, S> {
+    /// Splits the view into two mutable views at the given logical index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the total length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::SegmentsMut;
+    ///
+    /// let (mut front, mut back) = ([1, 2, 3], [4, 5]);
+    /// let segments = SegmentsMut::new(&mut front[..], &mut back[..]);
+    /// let (left, right) = segments.split_at_mut(1);
+    /// left.front[0] = 10;
+    /// right.back[1] = 50;
+    /// assert_eq!((front, back), ([10, 2, 3], [4, 50]));
+    /// ```
+    pub fn split_at_mut(self, index: usize) -> (Self, Self) {
+        let front_len = self.front.len();
+        if index <= front_len {
+            let (left, right) = self.front.split_slice_mut(index);
+            let (empty, back) = self.back.split_slice_mut(0);
+            (SegmentsMut::new(left, empty), SegmentsMut::new(right, back))
+        } else {
+            let (left, right) = self.back.split_slice_mut(index - front_len);
+            let (right, empty) = right.split_slice_mut(right.len());
+            (SegmentsMut::new(self.front, left), SegmentsMut::new(right, empty))
+        }
+    }
+}
+
+impl<'a, S: ?Sized + fmt::Debug> fmt::Debug for SegmentsMut<'a, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SegmentsMut")
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish()
+    }
+}
+
+impl<'a, S: ?Sized + Empty> Empty for SegmentsMut<'a, S> {
+    fn is_empty(&self) -> bool {
+        self.front.is_empty() && self.back.is_empty()
+    }
+}
+impl<'a, S: ?Sized + Len> Len for SegmentsMut<'a, S> {
+    fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+}
+
+/// A trait for viewing and splitting a collection as two slices.
+///
+/// This lets generic code handle contiguous collections and ring buffers alike.
+///
+/// Obtaining and splitting the segments of a collection must take a constant amount of time and
+/// space.
+pub trait SplitSegments: Len {
+    /// The type of the two slices.
+    type Slice: ?Sized;
+
+    /// Returns the two slices which hold the contents of the collection, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use len_trait::SplitSegments;
+    ///
+    /// let mut deque: VecDeque<_> = vec![2, 3].into();
+    /// deque.push_front(1);
+    ///
+    /// let segments = deque.as_segments();
+    /// let mut contents = segments.front.to_vec();
+    /// contents.extend_from_slice(segments.back);
+    /// assert_eq!(contents, [1, 2, 3]);
+    /// ```
+    fn as_segments(&self) -> Segments<'_, Self::Slice>;
+
+    /// Splits the segments of the collection at the given logical index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use len_trait::{Len, SplitSegments};
+    ///
+    /// fn check_split<C: SplitSegments<Slice = [u8]>>(collection: &C) {
+    ///     let (left, right) = collection.split_segments_at(2);
+    ///     assert_eq!(left.len(), 2);
+    ///     assert_eq!(right.len(), collection.len() - 2);
+    /// }
+    ///
+    /// let mut deque: VecDeque<u8> = vec![3, 4, 5].into();
+    /// deque.push_front(2);
+    /// deque.push_front(1);
+    /// check_split(&deque);
+    /// check_split(&vec![1, 2, 3, 4, 5]);
+    /// ```
+    fn split_segments_at(
+        &self,
+        index: usize,
+    ) -> (Segments<'_, Self::Slice>, Segments<'_, Self::Slice>)
+    where
+        Self::Slice: SplitSlice<usize, Slice = Self::Slice> + Len,
+    {
+        self.as_segments().split_at(index)
+    }
+}
+
+/// A trait for mutably viewing and splitting a collection as two slices.
+pub trait SplitSegmentsMut: SplitSegments {
+    /// Returns the two mutable slices which hold the contents of the collection, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use len_trait::SplitSegmentsMut;
+    ///
+    /// let mut deque: VecDeque<_> = vec![1, 2, 3].into();
+    /// let segments = deque.as_segments_mut();
+    /// segments.front[0] = 10;
+    /// assert_eq!(deque, [10, 2, 3]);
+    /// ```
+    fn as_segments_mut(&mut self) -> SegmentsMut<'_, Self::Slice>;
+
+    /// Splits the mutable segments of the collection at the given logical index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    fn split_segments_at_mut(
+        &mut self,
+        index: usize,
+    ) -> (SegmentsMut<'_, Self::Slice>, SegmentsMut<'_, Self::Slice>)
+    where
+        Self::Slice: SplitSliceMut<usize, Slice = Self::Slice> + Len,
+    {
+        self.as_segments_mut().split_at_mut(index)
+    }
+}
+
+/// A trait for rearranging a collection so that its contents are stored in one slice.
+///
+/// Making a collection contiguous must take at most a linear amount of time and space.
+pub trait MakeContiguous: SplitSegmentsMut {
+    /// Rearranges the collection so that it is contiguous, returning its contents as one slice.
+    ///
+    /// Afterwards, the `back` segment is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use len_trait::MakeContiguous;
+    ///
+    /// fn sort<C: MakeContiguous<Slice = [u32]>>(collection: &mut C) {
+    ///     collection.make_contiguous().sort();
+    /// }
+    ///
+    /// let mut deque: VecDeque<_> = vec![3, 1].into();
+    /// deque.push_front(2);
+    /// sort(&mut deque);
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    fn make_contiguous(&mut self) -> &mut Self::Slice;
+}
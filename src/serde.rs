@@ -0,0 +1,190 @@
+//! Deserialisation which pre-allocates collections using their length hint.
+//!
+//! The functions in this module can be used with `#[serde(deserialize_with = "...")]` for any
+//! collection implementing [`DeserializeWithCapacity`]. They call `WithCapacity::with_capacity`
+//! with the length the input claims to have, but never with more elements than fit in a maximum
+//! number of bytes, so that a hostile input can't force a huge allocation just by claiming to be
+//! long. The limit is in bytes rather than elements so that it means the same thing for large and
+//! small elements, like serde's own `size_hint::cautious`. Collections can still grow past the
+//! maximum while they're being filled, as the elements actually arrive.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Message {
+//!     #[serde(deserialize_with = "len_trait::serde::deserialize")]
+//!     tags: Vec<String>,
+//!     #[serde(deserialize_with = "len_trait::serde::deserialize_capped::<_, _, 4096>")]
+//!     headers: HashMap<String, String>,
+//! }
+//!
+//! let message: Message =
+//!     serde_json::from_str(r#"{"tags": ["a", "b"], "headers": {"x": "y"}}"#).unwrap();
+//! assert_eq!(message.tags, ["a", "b"]);
+//! assert_eq!(message.headers["x"], "y");
+//! ```
+//!
+//! [`DeserializeWithCapacity`]: trait.DeserializeWithCapacity.html
+use std::fmt;
+use std::mem;
+use std::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+
+use super::capacity::WithCapacity;
+
+/// The maximum number of bytes of elements reserved by [`deserialize`], which is 1 MiB.
+///
+/// [`deserialize`]: fn.deserialize.html
+pub const DEFAULT_MAX_BYTES: usize = 1024 * 1024;
+
+/// A trait for collections which can be deserialised with a bounded initial capacity.
+///
+/// Implementations should forward to [`deserialize_seq`] or [`deserialize_map`], depending on how
+/// the collection is represented.
+///
+/// [`deserialize_seq`]: fn.deserialize_seq.html
+/// [`deserialize_map`]: fn.deserialize_map.html
+pub trait DeserializeWithCapacity<'de>: WithCapacity + Sized {
+    /// Deserialises the collection, reserving space for at most `max_bytes` bytes of elements up
+    /// front.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::serde::DeserializeWithCapacity;
+    ///
+    /// let mut deserializer = serde_json::Deserializer::from_str("[1, 2, 3]");
+    /// let vec = Vec::<u32>::deserialize_with_capacity(&mut deserializer, 8).unwrap();
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    fn deserialize_with_capacity<D>(deserializer: D, max_bytes: usize) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+/// Deserialises a collection, reserving space for at most [`DEFAULT_MAX_BYTES`] bytes of elements
+/// up front.
+///
+/// [`DEFAULT_MAX_BYTES`]: constant.DEFAULT_MAX_BYTES.html
+pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+where
+    C: DeserializeWithCapacity<'de>,
+    D: Deserializer<'de>,
+{
+    C::deserialize_with_capacity(deserializer, DEFAULT_MAX_BYTES)
+}
+
+/// Deserialises a collection, reserving space for at most `MAX_BYTES` bytes of elements up front.
+pub fn deserialize_capped<'de, C, D, const MAX_BYTES: usize>(
+    deserializer: D,
+) -> Result<C, D::Error>
+where
+    C: DeserializeWithCapacity<'de>,
+    D: Deserializer<'de>,
+{
+    C::deserialize_with_capacity(deserializer, MAX_BYTES)
+}
+
+/// Deserialises a sequence of `T` into a collection, reserving space for at most `max_bytes` bytes
+/// of elements up front.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::de::value::{Error, SeqDeserializer};
+/// use len_trait::ExactIter;
+///
+/// // The input claims to be far too long, but only two `u64`s fit in 16 bytes.
+/// let claimed = ExactIter::new(vec![1u64, 2, 3].into_iter(), usize::MAX);
+/// let deserializer = SeqDeserializer::<_, Error>::new(claimed);
+/// let vec: Vec<_> = len_trait::serde::deserialize_seq::<_, u64, _>(deserializer, 16).unwrap();
+/// assert_eq!(vec, [1, 2, 3]);
+/// ```
+pub fn deserialize_seq<'de, C, T, D>(deserializer: D, max_bytes: usize) -> Result<C, D::Error>
+where
+    C: WithCapacity + Extend<T>,
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(SeqVisitor { max_bytes, marker: PhantomData })
+}
+
+/// Deserialises a map from `K` to `V` into a collection, reserving space for at most `max_bytes`
+/// bytes of entries up front, where each entry is measured as a `(K, V)` tuple.
+pub fn deserialize_map<'de, C, K, V, D>(deserializer: D, max_bytes: usize) -> Result<C, D::Error>
+where
+    C: WithCapacity + Extend<(K, V)>,
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(MapVisitor {
+        max_bytes,
+        marker: PhantomData,
+        entry: PhantomData,
+    })
+}
+
+/// Returns how many elements of type `T` can be reserved up front, given the claimed length.
+fn cautious<T>(size_hint: Option<usize>, max_bytes: usize) -> usize {
+    size_hint.unwrap_or(0).min(max_bytes / mem::size_of::<T>().max(1))
+}
+
+struct SeqVisitor<C, T> {
+    max_bytes: usize,
+    marker: PhantomData<fn() -> (C, T)>,
+}
+
+impl<'de, C, T> Visitor<'de> for SeqVisitor<C, T>
+where
+    C: WithCapacity + Extend<T>,
+    T: Deserialize<'de>,
+{
+    type Value = C;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<C, A::Error> {
+        let capacity = cautious::<T>(seq.size_hint(), self.max_bytes);
+        let mut collection = C::with_capacity(capacity);
+        while let Some(value) = seq.next_element()? {
+            collection.extend(Some(value));
+        }
+        Ok(collection)
+    }
+}
+
+struct MapVisitor<C, K, V> {
+    max_bytes: usize,
+    marker: PhantomData<fn() -> C>,
+    entry: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'de, C, K, V> Visitor<'de> for MapVisitor<C, K, V>
+where
+    C: WithCapacity + Extend<(K, V)>,
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    type Value = C;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<C, A::Error> {
+        let capacity = cautious::<(K, V)>(map.size_hint(), self.max_bytes);
+        let mut collection = C::with_capacity(capacity);
+        while let Some(entry) = map.next_entry()? {
+            collection.extend(Some(entry));
+        }
+        Ok(collection)
+    }
+}
@@ -0,0 +1,161 @@
+//! Traits involving strings, which may only be split at character boundaries.
+use super::len::Len;
+
+/// A trait for rounding indices of a string to its character boundaries.
+///
+/// Methods like [`LenMut::truncate`] and [`SplitAt::split_at`] panic when given an index which
+/// falls inside a character. The methods here instead round the index to the nearest boundary,
+/// which is useful when e.g. cutting user input down to a byte budget.
+///
+/// For `str`, a boundary is anywhere a `char` starts or ends. For `OsStr`, which may not be valid
+/// Unicode, a boundary is anywhere immediately before or after a valid character, plus the start
+/// and end of the string.
+///
+/// Checking for a boundary must take a constant amount of time and space. Rounding to a boundary
+/// must take at most a linear amount of time with respect to the distance moved.
+///
+/// [`LenMut::truncate`]: ../len/trait.LenMut.html#tymethod.truncate
+/// [`SplitAt::split_at`]: ../index/trait.SplitAt.html#tymethod.split_at
+pub trait CharBoundary: Len {
+    /// The type of string slices which this string can be split into.
+    type Slice: ?Sized;
+
+    /// Returns whether the given index lies on a character boundary.
+    ///
+    /// Zero and the length of the string are always boundaries, and indices past the end of the
+    /// string never are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::CharBoundary;
+    ///
+    /// let s = "中文";
+    /// assert!(CharBoundary::is_char_boundary(s, 3));
+    /// assert!(!CharBoundary::is_char_boundary(s, 4));
+    /// assert!(!CharBoundary::is_char_boundary(s, 7));
+    /// ```
+    ///
+    /// An `OsStr` can be split around bytes which aren't valid UTF-8, but not between them:
+    ///
+    /// ```rust
+    /// # #[cfg(unix)] {
+    /// use std::ffi::OsStr;
+    /// use std::os::unix::ffi::OsStrExt;
+    /// use len_trait::CharBoundary;
+    ///
+    /// // "a", two invalid bytes, "b" and "é".
+    /// let s = OsStr::from_bytes(b"a\xFF\xFEb\xC3\xA9");
+    /// let boundaries: Vec<_> = (0..=7).filter(|&i| CharBoundary::is_char_boundary(s, i)).collect();
+    /// assert_eq!(boundaries, [0, 1, 3, 4, 6]);
+    ///
+    /// let (left, right) = CharBoundary::split_at_floor(s, 2);
+    /// assert_eq!(left.as_bytes(), b"a");
+    /// assert_eq!(right.as_bytes(), b"\xFF\xFEb\xC3\xA9");
+    ///
+    /// let only_invalid = OsStr::from_bytes(b"\xFF\xFE\xFD");
+    /// assert!(CharBoundary::is_char_boundary(only_invalid, 0));
+    /// assert!(!CharBoundary::is_char_boundary(only_invalid, 1));
+    /// assert!(!CharBoundary::is_char_boundary(only_invalid, 2));
+    /// assert!(CharBoundary::is_char_boundary(only_invalid, 3));
+    /// # }
+    /// ```
+    fn is_char_boundary(&self, index: usize) -> bool;
+
+    /// Returns the largest character boundary which is at most `index`.
+    ///
+    /// Indices past the end of the string are rounded down to its length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::CharBoundary;
+    ///
+    /// let s = "中文";
+    /// assert_eq!(CharBoundary::floor_char_boundary(s, 4), 3);
+    /// assert_eq!(CharBoundary::floor_char_boundary(s, 2), 0);
+    /// assert_eq!(CharBoundary::floor_char_boundary(s, 100), 6);
+    /// ```
+    fn floor_char_boundary(&self, index: usize) -> usize {
+        let mut index = index.min(self.len());
+        while !CharBoundary::is_char_boundary(self, index) {
+            index -= 1;
+        }
+        index
+    }
+
+    /// Returns the smallest character boundary which is at least `index`.
+    ///
+    /// Indices past the end of the string are rounded down to its length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::CharBoundary;
+    ///
+    /// let s = "中文";
+    /// assert_eq!(CharBoundary::ceil_char_boundary(s, 4), 6);
+    /// assert_eq!(CharBoundary::ceil_char_boundary(s, 3), 3);
+    /// assert_eq!(CharBoundary::ceil_char_boundary(s, 100), 6);
+    /// ```
+    fn ceil_char_boundary(&self, index: usize) -> usize {
+        let mut index = index.min(self.len());
+        while !CharBoundary::is_char_boundary(self, index) {
+            index += 1;
+        }
+        index
+    }
+
+    /// Splits the string into two pieces at the largest character boundary which is at most
+    /// `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::CharBoundary;
+    ///
+    /// assert_eq!(CharBoundary::split_at_floor("中文", 4), ("中", "文"));
+    /// assert_eq!(CharBoundary::split_at_floor("中文", 100), ("中文", ""));
+    /// ```
+    fn split_at_floor(&self, index: usize) -> (&Self::Slice, &Self::Slice);
+
+    /// Splits the string into two pieces at the smallest character boundary which is at least
+    /// `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use len_trait::CharBoundary;
+    ///
+    /// assert_eq!(CharBoundary::split_at_ceil("中文", 1), ("中", "文"));
+    /// assert_eq!(CharBoundary::split_at_ceil("中文", 4), ("中文", ""));
+    /// ```
+    fn split_at_ceil(&self, index: usize) -> (&Self::Slice, &Self::Slice);
+}
+
+/// A trait for truncating a string at a character boundary.
+pub trait CharBoundaryMut: CharBoundary {
+    /// Truncates the string to the largest character boundary which is at most `len`.
+    ///
+    /// Unlike [`LenMut::truncate`], this never panics. If the string is less than `len` long, do
+    /// nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::ffi::OsString;
+    /// use len_trait::{CharBoundaryMut, Len};
+    ///
+    /// fn check_truncate_floor<S: CharBoundaryMut>(mut s: S, budget: usize, expected: usize) {
+    ///     s.truncate_floor(budget);
+    ///     assert_eq!(s.len(), expected);
+    /// }
+    ///
+    /// check_truncate_floor("Grüße".to_string(), 3, 2);
+    /// check_truncate_floor("Grüße".to_string(), 100, 7);
+    /// check_truncate_floor(OsString::from("Grüße"), 3, 2);
+    /// ```
+    ///
+    /// [`LenMut::truncate`]: ../len/trait.LenMut.html#tymethod.truncate
+    fn truncate_floor(&mut self, len: usize);
+}
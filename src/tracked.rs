@@ -0,0 +1,248 @@
+//! Wrappers which record how the capacity of a collection changes.
+use std::mem::MaybeUninit;
+use std::ops::Deref;
+
+use super::capacity::{BoundedPush, Capacity, CapacityMut, MaxCapacity, SpareCapacityMut,
+                      TryCapacityMut, WithCapacity};
+use super::error::{CapacityExceeded, IndexError, ReserveError};
+use super::len::{Clear, LenMut, TryLenMut};
+use super::string::CharBoundaryMut;
+
+/// Statistics recorded by a [`Tracked`] collection.
+///
+/// [`Tracked`]: struct.Tracked.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TrackedStats {
+    /// The number of operations which changed the capacity of the collection.
+    ///
+    /// For most collections, this is the number of times they reallocated.
+    pub capacity_changes: usize,
+
+    /// The greatest length observed.
+    pub peak_len: usize,
+
+    /// The greatest capacity observed.
+    pub peak_capacity: usize,
+
+    /// The greatest amount of unused capacity observed, i.e. capacity minus length.
+    pub peak_wasted_capacity: usize,
+}
+
+impl TrackedStats {
+    /// Records the state of a collection after an operation.
+    fn observe<C: Capacity>(&mut self, old_capacity: usize, collection: &C) {
+        let len = collection.len();
+        let capacity = collection.capacity();
+        if capacity != old_capacity {
+            self.capacity_changes += 1;
+        }
+        self.peak_len = self.peak_len.max(len);
+        self.peak_capacity = self.peak_capacity.max(capacity);
+        self.peak_wasted_capacity = self.peak_wasted_capacity.max(capacity.saturating_sub(len));
+    }
+}
+
+/// A wrapper which records how the length and capacity of a collection change over time.
+///
+/// Every operation from this crate which can change the length or capacity of the collection is
+/// forwarded to it, and the result is recorded in a [`TrackedStats`]. Elements can also be added
+/// with `Extend`. This is intended for tuning calls to `reserve`, by logging the stats once a
+/// collection has been filled.
+///
+/// Since the stats are about capacity, the traits which modify the collection are only
+/// implemented when it implements [`Capacity`].
+///
+/// # Examples
+///
+/// ```rust
+/// use len_trait::{CapacityMut, Tracked};
+///
+/// let mut vec: Tracked<Vec<u8>> = Tracked::default();
+/// vec.reserve_exact(4);
+/// vec.extend(0..6);
+/// vec.shrink_to_fit();
+///
+/// let stats = vec.stats();
+/// assert_eq!(stats.capacity_changes, 3);
+/// assert_eq!(stats.peak_len, 6);
+/// assert!(stats.peak_capacity >= 6);
+/// assert!(stats.peak_wasted_capacity >= 4);
+/// ```
+///
+/// The wrapped collection grows the same way it would without the wrapper:
+///
+/// ```rust
+/// use len_trait::{Capacity, Tracked};
+///
+/// let mut plain = Vec::new();
+/// let mut tracked = Tracked::new(Vec::new());
+/// let mut plain_changes = 0;
+/// for chunk in [0..1000, 0..10, 0..3000] {
+///     let old_capacity = plain.capacity();
+///     plain.extend(chunk.clone());
+///     plain_changes += (plain.capacity() != old_capacity) as usize;
+///     tracked.extend(chunk);
+/// }
+/// assert_eq!(tracked.capacity(), plain.capacity());
+/// assert_eq!(tracked.stats().capacity_changes, plain_changes);
+/// assert_eq!(tracked.stats().peak_capacity, plain.capacity());
+/// ```
+///
+/// [`TrackedStats`]: struct.TrackedStats.html
+/// [`Capacity`]: ../capacity/trait.Capacity.html
+#[derive(Clone, Debug)]
+pub struct Tracked<C> {
+    inner: C,
+    stats: TrackedStats,
+}
+
+impl<C: Capacity> Tracked<C> {
+    /// Wraps a collection, recording its current length and capacity.
+    pub fn new(inner: C) -> Self {
+        let mut stats = TrackedStats::default();
+        stats.observe(inner.capacity(), &inner);
+        Tracked { inner, stats }
+    }
+
+    /// Returns the stats recorded so far.
+    pub fn stats(&self) -> TrackedStats {
+        self.stats
+    }
+
+    /// Resets the stats, as if the collection had just been wrapped.
+    pub fn reset_stats(&mut self) {
+        self.stats = TrackedStats::default();
+        self.stats.observe(self.inner.capacity(), &self.inner);
+    }
+
+    /// Unwraps the collection.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    /// Runs an operation on the collection, recording its result.
+    fn track<R>(&mut self, f: impl FnOnce(&mut C) -> R) -> R {
+        let old_capacity = self.inner.capacity();
+        let ret = f(&mut self.inner);
+        self.stats.observe(old_capacity, &self.inner);
+        ret
+    }
+}
+
+impl<C: Capacity + Default> Default for Tracked<C> {
+    fn default() -> Self {
+        Tracked::new(C::default())
+    }
+}
+
+impl<C> Deref for Tracked<C> {
+    type Target = C;
+    fn deref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C> AsRef<C> for Tracked<C> {
+    fn as_ref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<T, C: Capacity + Extend<T>> Extend<T> for Tracked<C> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        // Pass on the elements from the size hint at once, so that the collection can reserve
+        // space for them as it would without the wrapper. Record the rest separately, so that
+        // every reallocation is counted.
+        let mut iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.track(|inner| inner.extend(iter.by_ref().take(lower)));
+        for value in iter {
+            self.track(|inner| inner.extend(Some(value)));
+        }
+    }
+}
+
+forward_shared!([] Tracked<C> => inner);
+
+impl<C: Clear + Capacity> Clear for Tracked<C> {
+    fn clear(&mut self) {
+        self.track(C::clear)
+    }
+}
+
+impl<C: LenMut + Capacity> LenMut for Tracked<C> {
+    fn truncate(&mut self, len: usize) {
+        self.track(|inner| inner.truncate(len))
+    }
+    fn split_off(&mut self, index: usize) -> Self {
+        Tracked::new(self.track(|inner| inner.split_off(index)))
+    }
+}
+
+impl<C: TryLenMut + Capacity> TryLenMut for Tracked<C> {
+    fn check_boundary(&self, index: usize) -> Result<(), IndexError> {
+        self.inner.check_boundary(index)
+    }
+}
+
+impl<C: CharBoundaryMut + Capacity> CharBoundaryMut for Tracked<C> {
+    fn truncate_floor(&mut self, len: usize) {
+        self.track(|inner| inner.truncate_floor(len))
+    }
+}
+
+impl<C: Capacity> Capacity for Tracked<C> {
+    fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+}
+
+impl<C: WithCapacity> WithCapacity for Tracked<C> {
+    fn with_capacity(capacity: usize) -> Self {
+        Tracked::new(C::with_capacity(capacity))
+    }
+}
+
+impl<C: CapacityMut> CapacityMut for Tracked<C> {
+    fn reserve(&mut self, additional: usize) {
+        self.track(|inner| inner.reserve(additional))
+    }
+    fn reserve_exact(&mut self, additional: usize) {
+        self.track(|inner| inner.reserve_exact(additional))
+    }
+    fn shrink_to_fit(&mut self) {
+        self.track(C::shrink_to_fit)
+    }
+}
+
+impl<C: TryCapacityMut> TryCapacityMut for Tracked<C> {
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ReserveError> {
+        self.track(|inner| inner.try_reserve(additional))
+    }
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), ReserveError> {
+        self.track(|inner| inner.try_reserve_exact(additional))
+    }
+}
+
+impl<C: SpareCapacityMut> SpareCapacityMut for Tracked<C> {
+    type Element = C::Element;
+    fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<C::Element>] {
+        self.inner.spare_capacity_mut()
+    }
+    unsafe fn set_len(&mut self, len: usize) {
+        // SAFETY: the caller upholds the same contract for the wrapped collection.
+        self.track(|inner| unsafe { inner.set_len(len) })
+    }
+}
+
+impl<C: MaxCapacity> MaxCapacity for Tracked<C> {
+    fn max_capacity(&self) -> usize {
+        self.inner.max_capacity()
+    }
+}
+
+impl<T, C: BoundedPush<T>> BoundedPush<T> for Tracked<C> {
+    fn try_push(&mut self, value: T) -> Result<(), CapacityExceeded<T>> {
+        self.track(|inner| inner.try_push(value))
+    }
+}
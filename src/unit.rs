@@ -0,0 +1,40 @@
+//! Units in which the length of a string can be measured.
+//!
+//! These types are markers for [`LenIn<U>`], and are never constructed.
+//!
+//! [`LenIn<U>`]: ../len/trait.LenIn.html
+
+/// Code values, i.e. the unit used by [`Len`].
+///
+/// For `str`, this is the number of UTF-8 bytes. For `OsStr`, this is the number of bytes in its
+/// platform-specific encoding, and for `CStr`, this excludes the nul terminator.
+///
+/// [`Len`]: ../len/trait.Len.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Bytes {}
+
+/// Unicode scalar values, i.e. `char`s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Chars {}
+
+/// UTF-16 code units, as used by JavaScript, Java and Windows APIs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Utf16 {}
+
+/// Extended grapheme clusters, i.e. user-perceived characters.
+///
+/// Requires the `unicode-segmentation` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use len_trait::LenIn;
+/// use len_trait::unit::{Chars, Graphemes};
+///
+/// let family = "👨\u{200d}👩\u{200d}👧";
+/// assert_eq!(LenIn::<Chars>::len_in(family), 5);
+/// assert_eq!(LenIn::<Graphemes>::len_in(family), 1);
+/// ```
+#[cfg(feature = "unicode-segmentation")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Graphemes {}